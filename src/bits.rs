@@ -0,0 +1,232 @@
+//! Methods inspecting and permuting individual bits of the integer, same as the ones primitive integers have.
+//!
+//! The integer is exactly ``206_265`` bits wide, so the upper-most bit is bit ``206_264``, which is the only bit of the upper-most byte.
+//! Bits past it are never counted as zeros.
+
+use const_for::const_for;
+
+use crate::{
+    BITS_U32, LIMBS, TOP_LIMB_MASK, const_bitor_assign, const_ilog2, const_shl, const_shr,
+    pure_rust_impl::limbs, u206265,
+};
+
+/// Number of bits actually used by the upper-most limb
+const TOP_LIMB_BITS: u32 = TOP_LIMB_MASK.count_ones();
+
+impl u206265 {
+    /// Returns the number of ones in the binary representation of ``self``.
+    pub const fn count_ones(&self) -> u32 {
+        let limbs = self.limbs();
+        let mut result = 0u32;
+        const_for!(i in 0..limbs.len() => result += limbs[i].count_ones());
+        result
+    }
+
+    /// Returns the number of zeros in the binary representation of ``self``.
+    #[inline]
+    pub const fn count_zeros(&self) -> u32 {
+        BITS_U32 - self.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of ``self``.
+    ///
+    /// Zero has all of its ``206_265`` bits leading.
+    #[inline]
+    pub const fn leading_zeros(&self) -> u32 {
+        match const_ilog2(self) {
+            Some(log) => BITS_U32 - 1 - log,
+            None => BITS_U32,
+        }
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of ``self``.
+    ///
+    /// Zero has all of its ``206_265`` bits trailing.
+    pub const fn trailing_zeros(&self) -> u32 {
+        let limbs = self.limbs();
+        const_for!(i in 0..limbs.len() => {
+            if limbs[i] != 0 {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    reason = "BITS_U32 exists, and limb count is less than number of bits - it's safe to cast"
+                )]
+                return i as u32 * u64::BITS + limbs[i].trailing_zeros();
+            }
+        });
+        BITS_U32
+    }
+
+    /// Returns the number of leading ones in the binary representation of ``self``, starting from bit ``206_264``.
+    pub const fn leading_ones(&self) -> u32 {
+        if self.significant_limbs() < LIMBS {
+            return 0;
+        }
+        // unused bits of the upper-most limb are shifted out of the way
+        let top = (self.0[LIMBS - 1] << (u64::BITS - TOP_LIMB_BITS)).leading_ones();
+        if top < TOP_LIMB_BITS {
+            return top;
+        }
+        let mut result = TOP_LIMB_BITS;
+        const_for!(i in (0..(LIMBS - 1)).rev() => {
+            let ones = self.0[i].leading_ones();
+            result += ones;
+            if ones < u64::BITS {
+                return result;
+            }
+        });
+        result
+    }
+
+    /// Returns the number of trailing ones in the binary representation of ``self``.
+    pub const fn trailing_ones(&self) -> u32 {
+        let limbs = self.limbs();
+        let mut result = 0u32;
+        // the upper-most limb is never all ones, so the loop always returns for `MAX`
+        const_for!(i in 0..limbs.len() => {
+            let ones = limbs[i].trailing_ones();
+            result += ones;
+            if ones < u64::BITS {
+                return result;
+            }
+        });
+        result
+    }
+
+    /// Returns the number of bits needed to represent ``self``, i.e. position of the highest one bit plus one.
+    ///
+    /// Zero needs no bits at all.
+    #[inline]
+    pub const fn bit_length(&self) -> u32 {
+        BITS_U32 - self.leading_zeros()
+    }
+}
+
+impl u206265 {
+    /// Shifts the bits to the left by ``n``, wrapping the truncated bits to the end of the resulting integer.
+    ///
+    /// Bit ``206_264`` wraps around to bit ``0``, since the integer is exactly ``206_265`` bits wide.
+    pub const fn rotate_left(&self, n: u32) -> Self {
+        let n = n % BITS_U32;
+        if n == 0 {
+            return self.const_clone();
+        }
+        let (mut result, _) = const_shl(self, n);
+        const_bitor_assign(&mut result, &const_shr(self, BITS_U32 - n).0);
+        result
+    }
+
+    /// Shifts the bits to the right by ``n``, wrapping the truncated bits to the beginning of the resulting integer.
+    ///
+    /// Bit ``0`` wraps around to bit ``206_264``, since the integer is exactly ``206_265`` bits wide.
+    #[inline]
+    pub const fn rotate_right(&self, n: u32) -> Self {
+        self.rotate_left(BITS_U32 - n % BITS_U32)
+    }
+
+    /// Reverses the order of bits in the integer. The least significant bit becomes the most significant bit ``206_264``, second least-significant bit becomes second most-significant bit, etc.
+    pub const fn reverse_bits(&self) -> Self {
+        let mut result = [0u64; LIMBS];
+        let limbs = self.limbs();
+        const_for!(i in 0..limbs.len() => result[LIMBS - 1 - i] = limbs[i].reverse_bits());
+        // limbs are wider than the integer, so the reversed bits end up that much higher
+        limbs::shr_assign(&mut result, u64::BITS - TOP_LIMB_BITS);
+        u206265::from_limbs(result)
+    }
+
+    /// Reverses the order of the whole bytes of the integer.
+    ///
+    /// The integer is ``25_783`` whole bytes and a single bit wide. The whole bytes are reversed, and the upper-most bit ``206_264`` stays in place.
+    ///
+    /// Unlike with primitive integers, ``x.swap_bytes().to_le_bytes()`` is **not** ``x.to_be_bytes()``: the lowest byte can't end up in the upper-most one, since that only has room for a single bit.
+    /// Instead, it's ``x.to_be_bytes()`` with the first byte (the one holding the upper-most bit) moved to the end:
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// let x = u206265::MAX - u206265::from(0xFFu8);
+    /// let mut expected = x.to_be_bytes();
+    /// expected.rotate_left(1);
+    /// assert_eq!(x.swap_bytes().to_le_bytes(), expected);
+    /// ```
+    pub const fn swap_bytes(&self) -> Self {
+        let mut result = [0u64; LIMBS];
+        let limbs = self.limbs();
+        let top_bit = TOP_LIMB_MASK & !(TOP_LIMB_MASK >> 1);
+        const_for!(i in 0..limbs.len() => {
+            // the upper-most byte is swapped separately
+            let limb = if i == LIMBS - 1 { limbs[i] & !top_bit } else { limbs[i] };
+            result[LIMBS - 1 - i] = limb.swap_bytes();
+        });
+        // lower-most byte is now the (empty) upper-most one, so everything is a byte too high
+        limbs::shr_assign(&mut result, u8::BITS);
+        if limbs.len() == LIMBS {
+            result[LIMBS - 1] |= limbs[LIMBS - 1] & top_bit;
+        }
+        u206265::from_limbs(result)
+    }
+}
+
+/// Integer with only bit `exp` set.
+const fn power_of_two(exp: u32) -> u206265 {
+    let limb = (exp / u64::BITS) as usize;
+    let mut result = [0u64; LIMBS];
+    result[limb] = 1 << (exp % u64::BITS);
+    u206265::from_limbs_bounded(result, limb + 1)
+}
+
+impl u206265 {
+    /// Returns ``true`` if and only if ``self == 2^k`` for some ``k``.
+    pub const fn is_power_of_two(&self) -> bool {
+        let limbs = self.limbs();
+        let Some((&high, low)) = limbs.split_last() else {
+            return false;
+        };
+        if !high.is_power_of_two() {
+            return false;
+        }
+        const_for!(i in 0..low.len() => {
+            if low[i] != 0 {
+                return false;
+            }
+        });
+        true
+    }
+
+    /// Returns the smallest power of two greater than or equal to ``self``, or [`None`], if it is greater than ``2^206264``.
+    pub const fn checked_next_power_of_two(&self) -> Option<Self> {
+        let Some(log) = const_ilog2(self) else {
+            return Some(Self::ONE);
+        };
+        if self.is_power_of_two() {
+            return Some(self.const_clone());
+        }
+        if log + 1 >= BITS_U32 {
+            return None;
+        }
+        Some(power_of_two(log + 1))
+    }
+
+    /// Returns the smallest power of two greater than or equal to ``self``.
+    ///
+    /// Overflow is handled the same way [`core::ops::Mul`] does it: the power of two wraps to zero.
+    #[inline]
+    pub const fn next_power_of_two(&self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(result) => result,
+            None => {
+                debug_assert!(false, "u206265 next_power_of_two overflow");
+                Self::ZERO
+            }
+        }
+    }
+
+    /// Returns the largest power of two less than or equal to ``self``, i.e. the highest one bit of ``self``.
+    ///
+    /// Zero has no such power, so zero is returned.
+    #[inline]
+    pub const fn prev_power_of_two(&self) -> Self {
+        match const_ilog2(self) {
+            Some(log) => power_of_two(log),
+            None => Self::ZERO,
+        }
+    }
+}
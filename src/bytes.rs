@@ -0,0 +1,97 @@
+//! Conversions between [`u206265`] and its bytes, in both byte orders.
+//!
+//! The integer is ``25_783`` whole bytes and a single bit wide, so it takes ``25_784`` bytes, with the upper-most one being either 0 or 1.
+
+use const_for::const_for;
+
+use crate::{BYTES, LIMBS, u206265};
+
+/// Represents failure to create [`u206265`] from a byte slice.
+#[allow(non_camel_case_types, reason = "foolish little rust-analyser...")]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum u206265FromSlice {
+    /// Slice is longer than ``25_784`` bytes.
+    TooLong {
+        /// Length of the slice.
+        len: usize,
+    },
+    /// Slice is exactly ``25_784`` bytes long, but the upper-most byte is greater than 1.
+    Overflow,
+}
+
+impl u206265 {
+    /// Creates [`u206265`] from little-endian bytes, i.e. with the least significant byte first. Same as [`create_bytes`](crate::create_bytes), but returns an error instead of panicking.
+    ///
+    /// Slice can be of any length up to ``25_784`` bytes; missing upper bytes are zero.
+    pub const fn try_from_le_slice(bytes: &[u8]) -> Result<Self, u206265FromSlice> {
+        let len = bytes.len();
+        if len > BYTES {
+            return Err(u206265FromSlice::TooLong { len });
+        }
+        if len == BYTES && bytes[BYTES - 1] > 1 {
+            return Err(u206265FromSlice::Overflow);
+        }
+        let mut result = [0u64; LIMBS];
+        const_for!(i in 0..len => result[i >> 3] |= (bytes[i] as u64) << ((i & 0b111) << 3));
+        Ok(Self::from_limbs_bounded(result, len.div_ceil(8)))
+    }
+
+    /// Creates [`u206265`] from big-endian bytes, i.e. with the most significant byte first.
+    ///
+    /// Slice can be of any length up to ``25_784`` bytes; missing upper bytes are zero.
+    pub const fn try_from_be_slice(bytes: &[u8]) -> Result<Self, u206265FromSlice> {
+        let len = bytes.len();
+        if len > BYTES {
+            return Err(u206265FromSlice::TooLong { len });
+        }
+        if len == BYTES && bytes[0] > 1 {
+            return Err(u206265FromSlice::Overflow);
+        }
+        let mut result = [0u64; LIMBS];
+        const_for!(i in 0..len => result[i >> 3] |= (bytes[len - 1 - i] as u64) << ((i & 0b111) << 3));
+        Ok(Self::from_limbs_bounded(result, len.div_ceil(8)))
+    }
+
+    /// Creates [`u206265`] from big-endian bytes. Same as [`create_bytes`](crate::create_bytes), but the most significant byte comes first.
+    ///
+    /// ### Panics
+    ///
+    /// Same as [`create_bytes`](crate::create_bytes):
+    /// 1. If input array is bigger than ``25_784`` bytes.
+    /// 2. If array is exactly ``25_784`` bytes, and the first byte is greater than 1.
+    pub const fn from_be_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        match Self::try_from_be_slice(&bytes) {
+            Ok(result) => result,
+            Err(u206265FromSlice::TooLong { .. }) => panic!("Input array is too big!"),
+            Err(u206265FromSlice::Overflow) => panic!("Upper-most byte should contain at most 1!"),
+        }
+    }
+
+    /// Returns little-endian bytes of the integer, i.e. with the least significant byte first.
+    pub const fn to_le_bytes(&self) -> [u8; BYTES] {
+        let mut result = [0u8; BYTES];
+        let limbs = self.limbs();
+        const_for!(i in 0..limbs.len() => {
+            let bytes = limbs[i].to_le_bytes();
+            const_for!(j in 0..8 => result[8 * i + j] = bytes[j]);
+        });
+        result
+    }
+
+    /// Returns big-endian bytes of the integer, i.e. with the most significant byte first.
+    pub const fn to_be_bytes(&self) -> [u8; BYTES] {
+        let mut result = [0u8; BYTES];
+        let limbs = self.limbs();
+        const_for!(i in 0..limbs.len() => {
+            let bytes = limbs[i].to_le_bytes();
+            const_for!(j in 0..8 => result[BYTES - 1 - (8 * i + j)] = bytes[j]);
+        });
+        result
+    }
+
+    /// Little-endian bytes of the integer, viewed in-place. Same as [`Self::to_le_bytes`], but without copying.
+    #[inline]
+    pub fn as_le_bytes(&self) -> &[u8; BYTES] {
+        self.le_bytes()
+    }
+}
@@ -17,6 +17,14 @@ const BITS_U32: u32 = {
     }
 };
 const BYTES: usize = BITS / 8 + (if (BITS & 0b111) > 0 { 1 } else { 0 }); // 206_265 / 8 + 1
+const LIMB_BITS: usize = u64::BITS as usize;
+const LIMBS: usize = BITS / LIMB_BITS + (if (BITS & 0b11_1111) > 0 { 1 } else { 0 }); // 206_265 / 64 + 1
+const _: () = assert!(
+    LIMBS * (LIMB_BITS / 8) == BYTES,
+    "limbs should cover exactly the same bytes"
+);
+/// Mask of bits actually used by the upper-most limb
+const TOP_LIMB_MASK: u64 = u64::MAX >> (LIMBS * LIMB_BITS - BITS);
 
 // little-endian
 /// An unsigned, 206265-bit integer.
@@ -31,9 +39,13 @@ const BYTES: usize = BITS / 8 + (if (BITS & 0b111) > 0 { 1 } else { 0 }); // 206
 /// assert_eq!(a + b, 5u32.into());
 /// ```
 #[allow(non_camel_case_types, reason = "foolish little rust-analyser...")]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, Eq)]
 #[cfg_attr(feature = "copy", derive(Copy))]
-pub struct u206265([u8; BYTES]); // last byte should only use one bit
+pub struct u206265(
+    [u64; LIMBS],
+    usize,
+    #[cfg(target_endian = "big")] [u8; BYTES],
+); // last limb should only use `TOP_LIMB_MASK` bits; second field is the number of significant limbs, kept up to date by every operation; on big-endian targets, third field holds little-endian bytes of the limbs, updated along with the second one
 
 impl u206265 {
     /// A minimum value
@@ -47,27 +59,93 @@ impl u206265 {
     /// A maximum value
     ///
     /// Equals $2^{206265} - 1$
-    pub const MAX: u206265 = u206265({
-        let mut all_max = [0xff; BYTES];
-        all_max[BYTES - 1] = 0b1;
+    pub const MAX: u206265 = u206265::from_limbs({
+        let mut all_max = [u64::MAX; LIMBS];
+        all_max[LIMBS - 1] = TOP_LIMB_MASK;
         all_max
     });
 
+    /// Wraps the limbs, counting the significant ones.
+    #[inline]
+    pub(crate) const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self::from_limbs_bounded(limbs, LIMBS)
+    }
+
+    /// Same as [`Self::from_limbs`], but only limbs below `bound` are looked at. The rest should be zero.
+    #[inline]
+    pub(crate) const fn from_limbs_bounded(limbs: [u64; LIMBS], bound: usize) -> Self {
+        #[allow(
+            clippy::init_numbered_fields,
+            reason = "the last field only exists on big-endian targets"
+        )]
+        let mut result = Self {
+            0: limbs,
+            1: 0,
+            #[cfg(target_endian = "big")]
+            2: [0; BYTES],
+        };
+        result.recount(bound);
+        result
+    }
+
+    /// Updates the number of significant limbs after an in-place operation. Only limbs below `bound` are looked at, the rest should be zero.
+    #[inline]
+    pub(crate) const fn recount(&mut self, bound: usize) {
+        // bytes are updated as well, including the ones of limbs that used to be significant, but might have been zeroed
+        #[cfg(target_endian = "big")]
+        {
+            let changed = if self.1 > bound { self.1 } else { bound };
+            let mut i = 0;
+            while i < changed {
+                let bytes = self.0[i].to_le_bytes();
+                let mut j = 0;
+                while j < bytes.len() {
+                    self.2[8 * i + j] = bytes[j];
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+        self.1 = pure_rust_impl::limbs::significant_len(self.0.split_at(bound).0);
+    }
+
+    /// Little-endian bytes of the limbs. On little-endian targets that's the limbs themselves, and on big-endian ones it's a copy, kept up to date by [`Self::recount`].
+    #[cfg(target_endian = "little")]
+    #[inline]
+    pub(crate) fn le_bytes(&self) -> &[u8; BYTES] {
+        ::bytemuck::cast_ref(&self.0)
+    }
+
+    /// Little-endian bytes of the limbs. On little-endian targets that's the limbs themselves, and on big-endian ones it's a copy, kept up to date by [`Self::recount`].
+    #[cfg(target_endian = "big")]
+    #[inline]
+    pub(crate) fn le_bytes(&self) -> &[u8; BYTES] {
+        &self.2
+    }
+
+    /// Number of limbs actually containing something. Zero has no significant limbs.
+    #[inline]
+    pub(crate) const fn significant_limbs(&self) -> usize {
+        self.1
+    }
+
+    /// Significant limbs of the integer, see [`Self::significant_limbs`].
+    #[inline]
+    pub(crate) const fn limbs(&self) -> &[u64] {
+        self.0.split_at(self.significant_limbs()).0
+    }
+
     /// Number of bytes actually containing something.
     ///
     /// You are unlikely to find any use in this method, but it is here, in case you need it.
     #[inline]
     pub const fn significant_bytes(&self) -> usize {
-        let mut i = BYTES - 1;
-        loop {
-            if self.0[i] > 0 {
-                return i + 1;
-            }
-            if i == 0 {
-                return 1;
-            }
-            i -= 1;
+        let limbs = self.significant_limbs();
+        if limbs == 0 {
+            return 1;
         }
+        let high_limb_bytes = (self.0[limbs - 1].ilog2() >> 3) as usize + 1;
+        (limbs - 1) * (LIMB_BITS / 8) + high_limb_bytes
     }
 
     /// Same as [`Self::significant_bytes`], but returns `u32` instead of `usize`.
@@ -85,33 +163,144 @@ impl u206265 {
     }
 
     /// A byte slice of internal memory, containing integer's data.
+    ///
+    /// Bytes are little-endian, i.e. the least significant one comes first.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// assert_eq!(u206265::from(0xAA_BBu16).significant_bytes_slice(), [0xBB, 0xAA]);
+    /// ```
     #[inline]
     pub fn significant_bytes_slice(&self) -> &[u8] {
-        &self.0[..self.significant_bytes()]
+        &self.le_bytes()[..self.significant_bytes()]
     }
 
     /// Same as [`Clone::clone`], but `const`.
     #[inline]
     pub const fn const_clone(&self) -> Self {
-        Self(self.0)
+        #[allow(
+            clippy::init_numbered_fields,
+            reason = "the last field only exists on big-endian targets"
+        )]
+        Self {
+            0: self.0,
+            1: self.1,
+            #[cfg(target_endian = "big")]
+            2: self.2,
+        }
+    }
+
+    /// Squares the integer. Same as `self * self`, but faster, see [`const_sqr`].
+    ///
+    /// Overflow is handled the same way [`core::ops::Mul`] does it.
+    #[inline]
+    pub const fn square(&self) -> Self {
+        let (result, overflow) = const_sqr(self);
+        debug_assert!(!overflow, "u206265 square overflow");
+        result
+    }
+
+    /// Raises ``self`` to the power of ``exp``, see [`const_pow`].
+    ///
+    /// Overflow is handled the same way [`core::ops::Mul`] does it.
+    #[inline]
+    pub const fn pow(&self, exp: u32) -> Self {
+        let (result, overflow) = const_pow(self, exp);
+        debug_assert!(!overflow, "u206265 pow overflow");
+        result
+    }
+
+    /// Same as [`Self::pow`], but the exponent is a [`u206265`] as well, see [`const_pow_u206265`].
+    #[inline]
+    pub const fn pow_u206265(&self, exp: &u206265) -> Self {
+        let (result, overflow) = const_pow_u206265(self, exp);
+        debug_assert!(!overflow, "u206265 pow overflow");
+        result
+    }
+
+    /// Finds the square root of the integer, rounded down, see [`const_isqrt`].
+    #[inline]
+    pub const fn isqrt(&self) -> Self {
+        const_isqrt(self)
+    }
+
+    /// Same as [`Self::isqrt`], see [`const_checked_isqrt`].
+    #[inline]
+    pub const fn checked_isqrt(&self) -> Option<Self> {
+        const_checked_isqrt(self)
+    }
+
+    /// Finds the square root of the integer, rounded down, along with the remainder, see [`const_sqrt_rem`].
+    ///
+    /// ### Returns
+    /// ``(root, remainder)``
+    #[inline]
+    pub const fn sqrt_rem(&self) -> (Self, Self) {
+        const_sqrt_rem(self)
+    }
+
+    /// Finds the ``n``-th root of the integer, rounded down, see [`const_iroot_rem`].
+    ///
+    /// ### Panics
+    /// If ``n == 0``.
+    #[inline]
+    pub const fn iroot(&self, n: u32) -> Self {
+        self.iroot_rem(n).0
+    }
+
+    /// Finds the ``n``-th root of the integer, rounded down, along with the remainder, see [`const_iroot_rem`].
+    ///
+    /// ### Returns
+    /// ``(root, remainder)``
+    ///
+    /// ### Panics
+    /// If ``n == 0``.
+    #[inline]
+    pub const fn iroot_rem(&self, n: u32) -> (Self, Self) {
+        const_iroot_rem(self, n).expect("Zeroth root")
+    }
+
+    /// Checks if the integer is the ``n``-th power of some integer.
+    ///
+    /// ### Panics
+    /// If ``n == 0``.
+    #[inline]
+    pub const fn is_perfect_kth_power(&self, n: u32) -> bool {
+        const_cmp(&self.iroot_rem(n).1, &Self::ZERO).is_eq()
     }
 }
 
+mod bits;
+mod bytes;
+mod overflow;
+mod parse;
+mod perfect_power;
 mod pure_rust_impl;
+mod radix;
+mod reciprocal;
+mod rounding;
 
 use core::{
-    fmt::{Display, LowerHex, UpperHex},
+    fmt::{Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex, Write},
+    hash::{Hash, Hasher},
     iter::{Product, Sum},
     ops::Not,
 };
 
+pub use bytes::u206265FromSlice;
+pub use parse::u206265FromStr;
 pub use pure_rust_impl::{
     const_add, const_add_assign, const_bitand, const_bitand_assign, const_bitor,
-    const_bitor_assign, const_bitxor, const_bitxor_assign, const_cmp, const_div, const_div_assign,
-    const_div_rem, const_ilog, const_ilog2, const_ilog10, const_mul, const_mul_assign,
-    const_not_assign, const_rem, const_rem_assign, const_shl, const_shl_assign, const_shr,
-    const_shr_assign, const_sub, const_sub_assign, create_bytes,
+    const_bitor_assign, const_bitxor, const_bitxor_assign, const_checked_isqrt, const_checked_pow,
+    const_cmp, const_div, const_div_assign, const_div_rem, const_div_rem_u64, const_div_u64,
+    const_div_u64_assign, const_ilog, const_ilog2, const_ilog10, const_iroot_rem, const_isqrt,
+    const_mul, const_mul_assign, const_mul_u64, const_mul_u64_assign, const_not_assign, const_pow,
+    const_pow_u206265, const_rem, const_rem_assign, const_rem_u64, const_shl, const_shl_assign,
+    const_shr, const_shr_assign, const_sqr, const_sqr_assign, const_sqrt_rem, const_sub,
+    const_sub_assign, create_bytes,
 };
+pub use reciprocal::Reciprocal;
+pub use rounding::Rounding;
 
 /// Represents failure to convert [`u206265`] into a smaller integer.
 ///
@@ -147,18 +336,17 @@ macro_rules! impl_unsigned {
                     const BITS_U32: u32 = $type::BITS;
                     const TYPE_BITS: usize = BITS_U32 as usize;
                     const TYPE_BYTES: usize = TYPE_BITS >> 3;
-                    use ::const_for::const_for;
-                    let mut bytes: [u8; TYPE_BYTES] = [0u8; TYPE_BYTES];
-                    const_for!(i in 0..TYPE_BYTES => {
-                        bytes[i] = self.0[i];
-                    });
                     let significant_length = self.significant_bytes();
-                    if significant_length > bytes.len() {
+                    if significant_length > TYPE_BYTES {
                         return Err(u206265ToUnsigned {
                             bytes_required: significant_length,
                         });
                     }
-                    Ok($type::from_le_bytes(bytes))
+                    // no primitive is wider than two limbs
+                    let value = self.0[0] as u128 | ((self.0[1] as u128) << LIMB_BITS);
+                    #[allow(clippy::cast_possible_truncation, reason = "Value was checked to fit right above")]
+                    let value = value as $type;
+                    Ok(value)
                 }
             }
 
@@ -338,11 +526,16 @@ impl u206265 {
 
 macro_rules! impl_op_common {
     ($op:ident) => {
+        ::paste::paste! {
+            impl_op_common!($op, [<const_ $op:lower _assign>]);
+        }
+    };
+    ($op:ident, $assign:ident) => {
         ::paste::paste! {
             impl<'rhs> ::core::ops::[<$op:camel Assign>]<&'rhs u206265> for u206265 {
                 #[inline]
                 fn [<$op:lower _assign>](&mut self, rhs: &'rhs u206265) {
-                    [<const_ $op:lower _assign>](self, rhs);
+                    $assign(self, rhs);
                 }
             }
 
@@ -385,7 +578,7 @@ macro_rules! impl_op_common {
 
 impl_op_common!(Add);
 impl_op_common!(Sub);
-impl_op_common!(Mul);
+impl_op_common!(Mul, mul_assign);
 impl_op_common!(Div);
 impl_op_common!(Rem);
 impl_op_common!(BitOr);
@@ -411,7 +604,35 @@ macro_rules! impl_op_overflow {
 
 impl_op_overflow!(Add);
 impl_op_overflow!(Sub);
-impl_op_overflow!(Mul);
+
+impl<'rhs> ::core::ops::Mul<&'rhs u206265> for &u206265 {
+    type Output = u206265;
+
+    #[inline]
+    fn mul(self, rhs: &'rhs u206265) -> Self::Output {
+        let (result, overflow) = mul(self, rhs);
+        debug_assert!(!overflow, "u206265 mul overflow");
+        result
+    }
+}
+
+/// Same as [`const_mul`], but large operands are multiplied on several threads, if `parallel` feature is enabled.
+#[inline]
+fn mul(lhs: &u206265, rhs: &u206265) -> (u206265, bool) {
+    #[cfg(feature = "parallel")]
+    if pure_rust_impl::parallel::mul_worthwhile(lhs, rhs) {
+        return pure_rust_impl::parallel::mul(lhs, rhs, pure_rust_impl::parallel::threads());
+    }
+    const_mul(lhs, rhs)
+}
+
+/// Same as [`const_mul_assign`], but large operands are multiplied on several threads, if `parallel` feature is enabled.
+#[inline]
+fn mul_assign(lhs: &mut u206265, rhs: &u206265) -> bool {
+    let (result, overflow) = mul(lhs, rhs);
+    *lhs = result;
+    overflow
+}
 
 macro_rules! impl_op_division {
     ($op:ident) => {
@@ -431,6 +652,80 @@ macro_rules! impl_op_division {
 impl_op_division!(Div);
 impl_op_division!(Rem);
 
+impl ::core::ops::MulAssign<u64> for u206265 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u64) {
+        let overflow = const_mul_u64_assign(self, rhs);
+        debug_assert!(!overflow, "u206265 mul overflow");
+    }
+}
+
+impl ::core::ops::Mul<u64> for u206265 {
+    type Output = u206265;
+
+    #[inline]
+    fn mul(mut self, rhs: u64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl ::core::ops::Mul<u64> for &u206265 {
+    type Output = u206265;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Self::Output {
+        let (result, overflow) = const_mul_u64(self, rhs);
+        debug_assert!(!overflow, "u206265 mul overflow");
+        result
+    }
+}
+
+impl ::core::ops::DivAssign<u64> for u206265 {
+    #[inline]
+    fn div_assign(&mut self, rhs: u64) {
+        const_div_u64_assign(self, rhs);
+    }
+}
+
+impl ::core::ops::Div<u64> for u206265 {
+    type Output = u206265;
+
+    #[inline]
+    fn div(mut self, rhs: u64) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl ::core::ops::Div<u64> for &u206265 {
+    type Output = u206265;
+
+    #[inline]
+    fn div(self, rhs: u64) -> Self::Output {
+        const_div_u64(self, rhs).expect("Division by zero")
+    }
+}
+
+/// Remainder always fits into [`u64`], so that's what it is.
+impl ::core::ops::Rem<u64> for &u206265 {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: u64) -> Self::Output {
+        const_rem_u64(self, rhs).expect("Division by zero")
+    }
+}
+
+impl ::core::ops::Rem<u64> for u206265 {
+    type Output = u64;
+
+    #[inline]
+    fn rem(self, rhs: u64) -> Self::Output {
+        const_rem_u64(&self, rhs).expect("Division by zero")
+    }
+}
+
 macro_rules! impl_op {
     ($op:ident) => {
         ::paste::paste! {
@@ -530,6 +825,20 @@ macro_rules! impl_sh {
 impl_sh!(Shl);
 impl_sh!(Shr);
 
+impl PartialEq for u206265 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.limbs() == other.limbs()
+    }
+}
+
+impl Hash for u206265 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.limbs().hash(state);
+    }
+}
+
 impl PartialOrd for u206265 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
@@ -566,72 +875,258 @@ impl Not for &u206265 {
 impl Sum for u206265 {
     #[inline]
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut sum = u206265::ZERO;
-        for num in iter {
-            sum += num;
+        #[cfg(feature = "parallel")]
+        {
+            pure_rust_impl::parallel::fold(
+                iter,
+                &u206265::ZERO,
+                |sum, num| *sum += num,
+                pure_rust_impl::parallel::FOLD_LIMBS_THRESHOLD,
+                pure_rust_impl::parallel::threads,
+            )
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut sum = u206265::ZERO;
+            for num in iter {
+                sum += num;
+            }
+            sum
         }
-        sum
     }
 }
 
 impl Product for u206265 {
     #[inline]
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut prod = u206265::ONE;
-        for num in iter {
-            prod *= num;
+        #[cfg(feature = "parallel")]
+        {
+            pure_rust_impl::parallel::fold(
+                iter,
+                &u206265::ONE,
+                // items are already multiplied on different threads, but overflow is still checked, same as by `*=`
+                |prod, num| {
+                    let overflow = const_mul_assign(prod, num);
+                    debug_assert!(!overflow, "u206265 mul overflow");
+                },
+                pure_rust_impl::parallel::FOLD_LIMBS_THRESHOLD,
+                pure_rust_impl::parallel::threads,
+            )
         }
-        prod
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut prod = u206265::ONE;
+            for num in iter {
+                prod *= num;
+            }
+            prod
+        }
+    }
+}
+
+/// Same as [`Formatter::pad_integral`] for a non-negative integer, but digits are written by `write_digits`, instead of being passed as a string.
+///
+/// There can be as many as ``206_265`` of them, so collecting digits into a string would take either an allocator, or a huge buffer on the stack.
+fn pad_integral(
+    f: &mut Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    write_digits: impl FnOnce(&mut Formatter<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let sign = if f.sign_plus() { "+" } else { "" };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let padding = f.width().map_or(0, |width| {
+        width.saturating_sub(sign.len() + prefix.len() + len)
+    });
+    if f.sign_aware_zero_pad() {
+        // zeros go between the prefix and the digits, regardless of fill and alignment
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        write_fill(f, '0', padding)?;
+        return write_digits(f);
+    }
+    let (pre, post) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, pre)?;
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    write_digits(f)?;
+    write_fill(f, fill, post)
+}
+
+/// Writes `fill` character `count` times.
+fn write_fill(f: &mut Formatter<'_>, fill: char, count: usize) -> core::fmt::Result {
+    for _ in 0..count {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl u206265 {
+    /// Number of digits, each taking `digit_bits` bits. Zero still has a single digit.
+    const fn digits_len(&self, digit_bits: u32) -> usize {
+        let len = self.bit_length().div_ceil(digit_bits) as usize;
+        if len == 0 { 1 } else { len }
     }
 }
 
 impl LowerHex for u206265 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let highest_byte = self.significant_bytes().saturating_sub(1);
-        <u8 as core::fmt::LowerHex>::fmt(&self.0[highest_byte], f)?;
-        for i in (0..highest_byte).rev() {
-            write!(f, "{:02x}", self.0[i])?;
-        }
-        Ok(())
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        pad_integral(f, "0x", self.digits_len(4), |f| {
+            let highest_limb = self.significant_limbs().saturating_sub(1);
+            write!(f, "{:x}", self.0[highest_limb])?;
+            for i in (0..highest_limb).rev() {
+                write!(f, "{:016x}", self.0[i])?;
+            }
+            Ok(())
+        })
     }
 }
 
 impl UpperHex for u206265 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let highest_byte = self.significant_bytes().saturating_sub(1);
-        <u8 as core::fmt::UpperHex>::fmt(&self.0[highest_byte], f)?;
-        for i in (0..highest_byte).rev() {
-            write!(f, "{:02X}", self.0[i])?;
-        }
-        Ok(())
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        pad_integral(f, "0x", self.digits_len(4), |f| {
+            let highest_limb = self.significant_limbs().saturating_sub(1);
+            write!(f, "{:X}", self.0[highest_limb])?;
+            for i in (0..highest_limb).rev() {
+                write!(f, "{:016X}", self.0[i])?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Binary for u206265 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        pad_integral(f, "0b", self.digits_len(1), |f| {
+            let highest_limb = self.significant_limbs().saturating_sub(1);
+            write!(f, "{:b}", self.0[highest_limb])?;
+            for i in (0..highest_limb).rev() {
+                write!(f, "{:064b}", self.0[i])?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Number of bits written as a single [`u64`] by [`Octal`] formatting. That's 21 octal digits, the most a limb can hold.
+const OCTAL_CHUNK_BITS: u32 = 63;
+
+/// Bits ``63 * index..63 * (index + 1)`` of the integer. Unlike hex digits, octal ones straddle limbs.
+const fn octal_chunk(val: &u206265, index: usize) -> u64 {
+    let offset = index * OCTAL_CHUNK_BITS as usize;
+    let limb = offset / u64::BITS as usize;
+    let shift = offset % u64::BITS as usize;
+    let mut chunk = val.0[limb] >> shift;
+    if shift > 0 && limb + 1 < LIMBS {
+        chunk |= val.0[limb + 1] << (u64::BITS as usize - shift);
+    }
+    chunk & (u64::MAX >> (u64::BITS - OCTAL_CHUNK_BITS))
+}
+
+impl Octal for u206265 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        pad_integral(f, "0o", self.digits_len(3), |f| {
+            let highest_chunk = (self.bit_length().saturating_sub(1) / OCTAL_CHUNK_BITS) as usize;
+            write!(f, "{:o}", octal_chunk(self, highest_chunk))?;
+            for i in (0..highest_chunk).rev() {
+                write!(f, "{:021o}", octal_chunk(self, i))?;
+            }
+            Ok(())
+        })
     }
 }
 
 impl Display for u206265 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        const TEN_38: u206265 = u206265::from_u128(10u128.pow(38));
-        const MAX_LENGTH: usize = 1612; // approx log10(u206265::MAX) / log10(u128::MAX)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if self == &Self::ZERO {
-            return write!(f, "{}", 0);
+            return pad_integral(f, "", 1, |f| f.write_str("0"));
+        }
+        let mut buf = [0u128; pure_rust_impl::decimal::CHUNKS];
+        #[cfg(feature = "parallel")]
+        if pure_rust_impl::parallel::to_chunks_worthwhile(self) {
+            pure_rust_impl::parallel::to_chunks(
+                &mut buf,
+                self.limbs(),
+                pure_rust_impl::parallel::threads(),
+            );
+        } else {
+            pure_rust_impl::decimal::to_chunks(&mut buf, self.limbs());
         }
-        let mut buf: [u128; MAX_LENGTH] = [0; MAX_LENGTH];
-        let mut buf_i = 0;
-        let mut val = self.const_clone();
-
-        while val != u206265::ZERO {
-            let (div, rem) = const_div_rem(&val, &TEN_38).unwrap();
-            val = div;
-            buf[buf_i] = rem.try_into_u128().unwrap();
-            buf_i += 1;
+        #[cfg(not(feature = "parallel"))]
+        pure_rust_impl::decimal::to_chunks(&mut buf, self.limbs());
+        let mut buf_i = buf.len() - 1;
+        while buf[buf_i] == 0 {
+            buf_i -= 1;
         }
 
-        buf_i -= 1;
-        write!(f, "{}", buf[buf_i])?;
-        for i in (0..buf_i).rev() {
-            write!(f, "{:038}", buf[i])?;
+        // every chunk below the upper-most one has exactly 38 digits
+        let len = buf[buf_i].ilog10() as usize + 1 + 38 * buf_i;
+        pad_integral(f, "", len, |f| {
+            write!(f, "{}", buf[buf_i])?;
+            for i in (0..buf_i).rev() {
+                write!(f, "{:038}", buf[i])?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Value of [`u206265`], as printed by its [`Debug`].
+struct DebugValue<'a>(&'a u206265);
+
+impl Debug for DebugValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let val = self.0;
+        if let Ok(small) = val.try_into_u128() {
+            // that takes care of `{:x?}` and `{:X?}` as well
+            return Debug::fmt(&small, f);
         }
+        // at least 33 hex digits, so the upper-most and the lower-most 16 never overlap
+        let (head, _) = const_shr(val, (val.digits_len(4) as u32 - 16) * 4);
+        write!(
+            f,
+            "0x{:x}…{:016x} ({} bits)",
+            head.0[0],
+            val.0[0],
+            val.bit_length()
+        )
+    }
+}
 
-        Ok(())
+/// Prints the value compactly, instead of all of the ``3223`` limbs.
+///
+/// Values fitting into a [`u128`] are printed the same way [`u128`] prints them, i.e. in decimal, unless ``{:x?}`` or ``{:X?}`` is used. The larger ones are printed as their upper-most and lower-most 16 hex digits, along with the bit length. Alternate form ``{:#?}`` adds some details about the representation.
+///
+/// Hex flags of [`Debug`] can't be read outside of the standard library, only passed along to the primitives, so the larger values are always in lowercase hex.
+///
+/// ```rust
+/// # use not_too_many_arcseconds::u206265;
+/// assert_eq!(format!("{:?}", u206265::from(42u8)), "u206265(42)");
+/// assert_eq!(format!("{:x?}", u206265::from(1000u16)), "u206265(3e8)");
+/// assert_eq!(
+///     format!("{:?}", u206265::MAX),
+///     "u206265(0x1fffffffffffffff…ffffffffffffffff (206265 bits))"
+/// );
+/// ```
+impl Debug for u206265 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let bits = self.bit_length();
+            // unlike `Self::significant_bytes`, zero has no bytes here, same as it has no bits or limbs
+            f.debug_struct("u206265")
+                .field("value", &DebugValue(self))
+                .field("bits", &bits)
+                .field("significant_bytes", &bits.div_ceil(u8::BITS))
+                .field("significant_limbs", &self.significant_limbs())
+                .finish()
+        } else {
+            f.debug_tuple("u206265").field(&DebugValue(self)).finish()
+        }
     }
 }
 
@@ -0,0 +1,196 @@
+//! Method families of primitive integers, differing in the way they treat an overflow:
+//! - `checked_*` return [`None`]
+//! - `wrapping_*` wrap around the boundary of the type
+//! - `overflowing_*` wrap around, and report that overflow had occurred
+//! - `saturating_*` stop at the boundary of the type
+//!
+//! Only the methods unsigned primitives have are here, so there's no `saturating_neg` or `saturating_shl`, for example.
+
+use crate::{
+    BITS_U32, const_add, const_checked_pow, const_div, const_mul, const_pow, const_rem, const_shl,
+    const_shr, const_sub, u206265,
+};
+
+macro_rules! overflowing_family {
+    ($op:ident, $rhs:ty, $saturated:ident, $what:literal) => {
+        ::paste::paste! {
+            impl u206265 {
+                #[doc = concat!("Finds ", $what, ", along with a flag, indicating if an arithmetic overflow had occurred. In that case, the wrapped value is returned.")]
+                #[doc = ""]
+                #[doc = concat!("Same as [`", stringify!([<const_ $op>]), "`].")]
+                #[inline]
+                pub const fn [<overflowing_ $op>](&self, rhs: $rhs) -> (Self, bool) {
+                    [<const_ $op>](self, rhs)
+                }
+
+                #[doc = concat!("Finds ", $what, ", returning [`None`] if overflow had occurred.")]
+                #[inline]
+                pub const fn [<checked_ $op>](&self, rhs: $rhs) -> Option<Self> {
+                    match [<const_ $op>](self, rhs) {
+                        (result, false) => Some(result),
+                        (_, true) => None,
+                    }
+                }
+
+                #[doc = concat!("Finds ", $what, ", wrapping around the boundary of the type.")]
+                #[inline]
+                pub const fn [<wrapping_ $op>](&self, rhs: $rhs) -> Self {
+                    [<const_ $op>](self, rhs).0
+                }
+
+                #[doc = concat!("Finds ", $what, ", returning [`u206265::", stringify!($saturated), "`] if overflow had occurred.")]
+                #[inline]
+                pub const fn [<saturating_ $op>](&self, rhs: $rhs) -> Self {
+                    match [<const_ $op>](self, rhs) {
+                        (result, false) => result,
+                        (_, true) => Self::$saturated,
+                    }
+                }
+            }
+        }
+    };
+}
+
+overflowing_family!(add, &u206265, MAX, "``self + rhs``");
+overflowing_family!(sub, &u206265, ZERO, "``self - rhs``");
+overflowing_family!(mul, &u206265, MAX, "``self * rhs``");
+
+impl u206265 {
+    /// Raises ``self`` to the power of ``exp``, along with a flag, indicating if an arithmetic overflow had occurred. In that case, the wrapped value is returned.
+    ///
+    /// Same as [`const_pow`].
+    #[inline]
+    pub const fn overflowing_pow(&self, exp: u32) -> (Self, bool) {
+        const_pow(self, exp)
+    }
+
+    /// Raises ``self`` to the power of ``exp``, returning [`None`] if overflow had occurred.
+    ///
+    /// Same as [`const_checked_pow`], so it returns as soon as overflow is guaranteed.
+    #[inline]
+    pub const fn checked_pow(&self, exp: u32) -> Option<Self> {
+        const_checked_pow(self, exp)
+    }
+
+    /// Raises ``self`` to the power of ``exp``, wrapping around the boundary of the type.
+    #[inline]
+    pub const fn wrapping_pow(&self, exp: u32) -> Self {
+        const_pow(self, exp).0
+    }
+
+    /// Raises ``self`` to the power of ``exp``, returning [`u206265::MAX`] if overflow had occurred.
+    ///
+    /// Returns as soon as overflow is guaranteed, see [`const_checked_pow`].
+    #[inline]
+    pub const fn saturating_pow(&self, exp: u32) -> Self {
+        match const_checked_pow(self, exp) {
+            Some(result) => result,
+            None => Self::MAX,
+        }
+    }
+}
+
+macro_rules! division_family {
+    ($op:ident, $what:literal) => {
+        ::paste::paste! {
+            impl u206265 {
+                #[doc = concat!("Finds ", $what, ", returning [`None`] if ``rhs == 0``.")]
+                #[doc = ""]
+                #[doc = concat!("Same as [`", stringify!([<const_ $op>]), "`].")]
+                #[inline]
+                pub const fn [<checked_ $op>](&self, rhs: &u206265) -> Option<Self> {
+                    [<const_ $op>](self, rhs)
+                }
+
+                #[doc = concat!("Finds ", $what, ". Unsigned division never overflows, so this is the same as the operator.")]
+                #[doc = ""]
+                #[doc = "### Panics"]
+                #[doc = "If ``rhs == 0``."]
+                #[inline]
+                pub const fn [<wrapping_ $op>](&self, rhs: &u206265) -> Self {
+                    [<const_ $op>](self, rhs).expect("Division by zero")
+                }
+
+                #[doc = concat!("Finds ", $what, ". Unsigned division never overflows, so the flag is always ``false``.")]
+                #[doc = ""]
+                #[doc = "### Panics"]
+                #[doc = "If ``rhs == 0``."]
+                #[inline]
+                pub const fn [<overflowing_ $op>](&self, rhs: &u206265) -> (Self, bool) {
+                    (self.[<wrapping_ $op>](rhs), false)
+                }
+            }
+        }
+    };
+}
+
+division_family!(div, "``self / rhs``");
+division_family!(rem, "``self % rhs``");
+
+impl u206265 {
+    /// Finds ``self / rhs``. Unsigned division never overflows, so this is the same as the operator.
+    ///
+    /// ### Panics
+    /// If ``rhs == 0``.
+    #[inline]
+    pub const fn saturating_div(&self, rhs: &u206265) -> Self {
+        self.wrapping_div(rhs)
+    }
+
+    /// Finds ``-self``, along with a flag, indicating if an arithmetic overflow had occurred. In that case, the wrapped value is returned.
+    ///
+    /// Any number but zero overflows, as it is unsigned.
+    #[inline]
+    pub const fn overflowing_neg(&self) -> (Self, bool) {
+        const_sub(&Self::ZERO, self)
+    }
+
+    /// Finds ``-self``, returning [`None`] if overflow had occurred, i.e. for any number but zero.
+    #[inline]
+    pub const fn checked_neg(&self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (result, false) => Some(result),
+            (_, true) => None,
+        }
+    }
+
+    /// Finds ``-self``, wrapping around the boundary of the type, i.e. $2^{206265} - \text{self}$.
+    #[inline]
+    pub const fn wrapping_neg(&self) -> Self {
+        self.overflowing_neg().0
+    }
+}
+
+macro_rules! shift_family {
+    ($op:ident, $what:literal) => {
+        ::paste::paste! {
+            impl u206265 {
+                #[doc = concat!("Finds ", $what, ", along with a flag, indicating if shift is not less than the number of bits. In that case, the shift is taken modulo the number of bits.")]
+                #[doc = ""]
+                #[doc = concat!("Same as [`", stringify!([<const_ $op>]), "`].")]
+                #[inline]
+                pub const fn [<overflowing_ $op>](&self, rhs: u32) -> (Self, bool) {
+                    [<const_ $op>](self, rhs)
+                }
+
+                #[doc = concat!("Finds ", $what, ", returning [`None`] if shift is not less than the number of bits.")]
+                #[inline]
+                pub const fn [<checked_ $op>](&self, rhs: u32) -> Option<Self> {
+                    if rhs >= BITS_U32 {
+                        return None;
+                    }
+                    Some([<const_ $op>](self, rhs).0)
+                }
+
+                #[doc = concat!("Finds ", $what, ", taking the shift modulo the number of bits.")]
+                #[inline]
+                pub const fn [<wrapping_ $op>](&self, rhs: u32) -> Self {
+                    [<const_ $op>](self, rhs).0
+                }
+            }
+        }
+    };
+}
+
+shift_family!(shl, "``self << rhs``");
+shift_family!(shr, "``self >> rhs``");
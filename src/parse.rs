@@ -0,0 +1,126 @@
+//! Parsing [`u206265`] from text.
+//!
+//! Multiplying the whole integer by the radix for every digit is painfully slow, so digits are first gathered into a machine word, as many as it fits, and only then the integer is multiplied by the corresponding power of the radix.
+
+use core::str::FromStr;
+
+use crate::{LIMBS, TOP_LIMB_MASK, pure_rust_impl::limbs, u206265};
+
+/// Represents failure to parse [`u206265`] from a string.
+#[allow(non_camel_case_types, reason = "foolish little rust-analyser...")]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum u206265FromStr {
+    /// String contains no digits.
+    Empty,
+    /// String contains a character that is not a digit in the radix.
+    InvalidDigit {
+        /// Byte offset of the character in the string.
+        position: usize,
+    },
+    /// Number is greater than [`u206265::MAX`].
+    Overflow,
+}
+
+/// Value of an ASCII digit, if it's less than `radix`.
+const fn digit_value(byte: u8, radix: u32) -> Option<u64> {
+    let value = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'z' => byte - b'a' + 10,
+        b'A'..=b'Z' => byte - b'A' + 10,
+        _ => return None,
+    };
+    if (value as u32) < radix {
+        Some(value as u64)
+    } else {
+        None
+    }
+}
+
+/// Multiplies `result` by `scale` and adds `chunk`, extending its significant length `len`, if needed.
+///
+/// ### Returns
+/// If the result is greater than [`u206265::MAX`].
+const fn push_chunk(result: &mut [u64; LIMBS], len: &mut usize, scale: u64, chunk: u64) -> bool {
+    let significant = limbs::range_mut(result, 0, *len);
+    let carry = limbs::mul_limb_assign(significant, scale);
+    // carry is less than the scale, so adding one can't overflow
+    let carry = carry + limbs::add_assign(significant, &[chunk]) as u64;
+    if carry > 0 {
+        if *len == LIMBS {
+            return true;
+        }
+        result[*len] = carry;
+        *len += 1;
+    }
+    result[LIMBS - 1] > TOP_LIMB_MASK
+}
+
+impl u206265 {
+    /// Parses [`u206265`] from digits in the given radix. Same as with [`u64::from_str_radix`], a single leading ``+`` is allowed, and letters can be of either case.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::{u206265, u206265FromStr};
+    /// assert_eq!(u206265::from_str_radix("ff", 16), Ok(u206265::from(255u8)));
+    /// assert_eq!(u206265::from_str_radix("+101", 2), Ok(u206265::from(5u8)));
+    /// assert_eq!(u206265::from_str_radix("12a", 10), Err(u206265FromStr::InvalidDigit { position: 2 }));
+    /// ```
+    ///
+    /// ### Panics
+    /// If ``radix`` is not in range ``2..=36``.
+    pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, u206265FromStr> {
+        assert!(2 <= radix && radix <= 36, "Radix should be in range 2..=36");
+        let bytes = src.as_bytes();
+        let mut start = 0;
+        if !bytes.is_empty() && bytes[0] == b'+' {
+            start = 1;
+        }
+        if start == bytes.len() {
+            return if start == 0 {
+                Err(u206265FromStr::Empty)
+            } else {
+                Err(u206265FromStr::InvalidDigit { position: 0 })
+            };
+        }
+
+        let radix = radix as u64;
+        let mut result = [0u64; LIMBS];
+        let mut len = 1;
+        let mut chunk = 0u64;
+        let mut scale = 1u64;
+        let mut i = start;
+        while i < bytes.len() {
+            let Some(digit) = digit_value(bytes[i], radix as u32) else {
+                // same as std, digits before the invalid one might have overflowed already, and that's reported first
+                if scale > 1 && push_chunk(&mut result, &mut len, scale, chunk) {
+                    return Err(u206265FromStr::Overflow);
+                }
+                return Err(u206265FromStr::InvalidDigit { position: i });
+            };
+            chunk = chunk * radix + digit;
+            scale *= radix;
+            // flushing the chunk, once there's no space left for another digit
+            if scale > u64::MAX / radix {
+                if push_chunk(&mut result, &mut len, scale, chunk) {
+                    return Err(u206265FromStr::Overflow);
+                }
+                chunk = 0;
+                scale = 1;
+            }
+            i += 1;
+        }
+        if scale > 1 && push_chunk(&mut result, &mut len, scale, chunk) {
+            return Err(u206265FromStr::Overflow);
+        }
+        Ok(Self::from_limbs_bounded(result, len))
+    }
+}
+
+impl FromStr for u206265 {
+    type Err = u206265FromStr;
+
+    /// Parses [`u206265`] from decimal digits, see [`u206265::from_str_radix`].
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
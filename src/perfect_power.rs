@@ -0,0 +1,197 @@
+//! Detection of perfect powers, i.e. integers of form $\text{base}^\text{exp}$ with $\text{exp} \ge 2$.
+//!
+//! Extracting a root is expensive, so most candidates are rejected by cheap checks first:
+//! - Squares are filtered by their residues modulo 64, 63, 65 and 11, same as GMP does it. Less than 1% of non-squares get past that.
+//! - Odd $p$-th powers are filtered by their residues modulo primes $q = 2kp + 1$, since only about $1/p$ of residues modulo such a prime are $p$-th powers.
+//! - Odd roots fitting into a limb are simply found modulo $2^{64}$, since raising odd integers to odd powers is a permutation there.
+
+use crate::{
+    const_checked_pow, const_cmp, const_ilog2, const_iroot_rem, const_rem_u64, const_sqrt_rem,
+    u206265,
+};
+
+/// Finds which residues modulo `M` are squares.
+const fn squares<const M: usize>() -> [bool; M] {
+    let mut table = [false; M];
+    let mut i = 0;
+    while i < M {
+        table[i * i % M] = true;
+        i += 1;
+    }
+    table
+}
+
+const SQUARES_64: [bool; 64] = squares();
+const SQUARES_63: [bool; 63] = squares();
+const SQUARES_65: [bool; 65] = squares();
+const SQUARES_11: [bool; 11] = squares();
+
+/// Number of primes, residues modulo which an odd exponent is checked against, before the root is extracted.
+const RESIDUE_CHECKS: u32 = 3;
+
+impl u206265 {
+    /// Checks if the integer is a square of some integer.
+    ///
+    /// Most of the non-squares are rejected by their residues, before the root is extracted, see [`Self::isqrt`].
+    #[inline]
+    pub const fn is_perfect_square(&self) -> bool {
+        exact_sqrt(self).is_some()
+    }
+
+    /// Finds the smallest base and the largest exponent, such that ``base.pow(exp) == self``, if the integer is a perfect power.
+    ///
+    /// Zero and one are powers of themselves with any exponent, so there's no largest one, and [`None`] is returned for them.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// assert_eq!(u206265::from(64u8).perfect_power(), Some((2u8.into(), 6)));
+    /// assert_eq!(u206265::from(36u8).perfect_power(), Some((6u8.into(), 2)));
+    /// assert_eq!(u206265::from(12u8).perfect_power(), None);
+    /// ```
+    ///
+    /// Prime exponents are tried in increasing order, up to the logarithm of the base, see [`const_ilog2`]. Every time the base turns out to be a power, it's replaced with the root.
+    pub const fn perfect_power(&self) -> Option<(Self, u32)> {
+        let mut base = self.const_clone();
+        let mut exp = 1u32;
+        let mut p = 2u32;
+        // base is at least 2^p, so exponent can't be above the logarithm
+        while let Some(log) = const_ilog2(&base)
+            && p <= log
+        {
+            if is_prime(p as u64)
+                && let Some(root) = exact_root(&base, p, log)
+            {
+                // root might be a p-th power as well
+                base = root;
+                exp *= p;
+            } else {
+                p += 1;
+            }
+        }
+        if exp == 1 { None } else { Some((base, exp)) }
+    }
+}
+
+/// Finds the square root of `val`, if it is a perfect square.
+const fn exact_sqrt(val: &u206265) -> Option<u206265> {
+    let low = match val.limbs().first() {
+        Some(&low) => low,
+        None => 0,
+    };
+    if !SQUARES_64[(low % 64) as usize] {
+        return None;
+    }
+    let residue = const_rem_u64(val, 63 * 65 * 11).expect("Divisor is not zero");
+    if !SQUARES_63[(residue % 63) as usize]
+        || !SQUARES_65[(residue % 65) as usize]
+        || !SQUARES_11[(residue % 11) as usize]
+    {
+        return None;
+    }
+    match const_sqrt_rem(val) {
+        (root, remainder) if remainder.significant_limbs() == 0 => Some(root),
+        _ => None,
+    }
+}
+
+/// Finds the `p`-th root of `val`, if it is a perfect `p`-th power. `p` should be prime, and `log` should be the logarithm of `val`.
+const fn exact_root(val: &u206265, p: u32, log: u32) -> Option<u206265> {
+    if p == 2 {
+        return exact_sqrt(val);
+    }
+    // every prime factor comes in multiples of p, including two
+    let trailing_zeros = val.trailing_zeros();
+    if !trailing_zeros.is_multiple_of(p) {
+        return None;
+    }
+    if trailing_zeros == 0 && log / p < u64::BITS {
+        // root is odd and fits into a limb, so it's the only root modulo 2^64
+        let root = odd_root(val.limbs()[0], p);
+        // root^p should have exactly log + 1 bits
+        let root_log = root.ilog2();
+        if root_log * p > log || (root_log + 1) * p <= log {
+            return None;
+        }
+        let root = u206265::from_u64(root);
+        return match const_checked_pow(&root, p) {
+            Some(power) if const_cmp(&power, val).is_eq() => Some(root),
+            _ => None,
+        };
+    }
+
+    let step = 2 * p as u64;
+    let mut q = step + 1;
+    let mut checks = 0;
+    while checks < RESIDUE_CHECKS {
+        if is_prime(q) {
+            let residue = const_rem_u64(val, q).expect("Divisor is not zero");
+            // by Euler's criterion, nonzero p-th powers are exactly the residues with this being one
+            if residue != 0 && pow_mod(residue, (q - 1) / p as u64, q) != 1 {
+                return None;
+            }
+            checks += 1;
+        }
+        q += step;
+    }
+    match const_iroot_rem(val, p) {
+        Some((root, remainder)) if remainder.significant_limbs() == 0 => Some(root),
+        _ => None,
+    }
+}
+
+/// Checks if `n` is prime by trial division. Only used for small numbers.
+const fn is_prime(n: u64) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+    if n.is_multiple_of(2) {
+        return false;
+    }
+    let mut divisor = 3;
+    while divisor * divisor <= n {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 2;
+    }
+    true
+}
+
+/// Finds $\text{base}^\text{exp} \bmod m$.
+const fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1u128;
+    let mut square = (base % m) as u128;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * square % m as u128;
+        }
+        square = square * square % m as u128;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Finds the `p`-th root of odd `val` modulo $2^{64}$, for odd `p`.
+///
+/// Odd residues form a group of order $2^{63}$, in which $x^{2^{62}} = 1$. So raising to the power of inverse of `p` modulo $2^{62}$ undoes raising to the power of `p`.
+const fn odd_root(val: u64, p: u32) -> u64 {
+    // Newton's iteration for the inverse doubles correct bits, and any odd number is its own inverse modulo 8
+    let p = p as u64;
+    let mut inverse = p;
+    let mut i = 0;
+    while i < 5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inverse)));
+        i += 1;
+    }
+    let mut exp = inverse & (u64::MAX >> 2);
+    let mut result = 1u64;
+    let mut square = val;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.wrapping_mul(square);
+        }
+        square = square.wrapping_mul(square);
+        exp >>= 1;
+    }
+    result
+}
@@ -2,7 +2,16 @@ use core::cmp::Ordering;
 
 use const_for::const_for;
 
-use crate::{BITS_U32, BYTES, u206265};
+use crate::{BITS, BITS_U32, LIMB_BITS, LIMBS, TOP_LIMB_MASK, u206265, u206265FromSlice};
+
+pub(crate) mod barrett;
+pub(crate) mod decimal;
+pub(crate) mod div;
+pub(crate) mod limbs;
+pub(crate) mod mul;
+pub(crate) mod ntt;
+#[cfg(feature = "parallel")]
+pub(crate) mod parallel;
 
 /// Creates [`u206265`] from provided little-endian bytes. Can be used in constant context.
 ///
@@ -36,32 +45,27 @@ use crate::{BITS_U32, BYTES, u206265};
 ///
 /// Though in this case, you probably better off using [`u206265::from_u32`].
 pub const fn create_bytes<const N: usize>(bytes: [u8; N]) -> u206265 {
-    assert!(N <= BYTES, "Input array is too big!");
-    if N == BYTES {
-        assert!(
-            bytes[BYTES - 1] <= 1,
-            "Upper-most byte should contain at most 1!"
-        );
+    match u206265::try_from_le_slice(&bytes) {
+        Ok(result) => result,
+        Err(u206265FromSlice::TooLong { .. }) => panic!("Input array is too big!"),
+        Err(u206265FromSlice::Overflow) => panic!("Upper-most byte should contain at most 1!"),
     }
-    let mut result = [0u8; BYTES];
-    const_for!(i in 0..N => result[i] = bytes[i]);
-    u206265(result)
 }
 
 /// Compares two [`u206265`]s. Same as [`Ord::cmp`], but can be used in constant context.
 pub const fn const_cmp(lhs: &u206265, rhs: &u206265) -> Ordering {
-    let lhs_bytes = lhs.significant_bytes();
-    let rhs_bytes = rhs.significant_bytes();
-    if lhs_bytes < rhs_bytes {
+    let lhs_limbs = lhs.significant_limbs();
+    let rhs_limbs = rhs.significant_limbs();
+    if lhs_limbs < rhs_limbs {
         return Ordering::Less;
     }
-    if lhs_bytes > rhs_bytes {
+    if lhs_limbs > rhs_limbs {
         return Ordering::Greater;
     }
-    assert!(lhs_bytes == rhs_bytes);
-    const_for!(b in (0..lhs_bytes).rev() => {
-        let lhs = lhs.0[b];
-        let rhs = rhs.0[b];
+    assert!(lhs_limbs == rhs_limbs);
+    const_for!(l in (0..lhs_limbs).rev() => {
+        let lhs = lhs.0[l];
+        let rhs = rhs.0[l];
         if lhs < rhs {
             return Ordering::Less;
         }
@@ -91,21 +95,36 @@ pub const fn const_shl_assign(lhs: &mut u206265, mut rhs: u32) -> bool {
         overflow = false;
     }
 
-    // first, apply the whole-byte shift
-    let byte_shift = (rhs >> 3) as usize;
-    if byte_shift > 0 {
-        const_for!(i in (byte_shift..BYTES).rev() => lhs.0[i] = lhs.0[i-byte_shift]);
-        const_for!(i in 0..byte_shift => lhs.0[i] = 0);
+    let len = lhs.significant_limbs();
+    if len == 0 {
+        return overflow;
     }
 
-    // then, the subbyte shift
-    let subbyte_shift = rhs & 0b111;
-    let mut carry = 0u16;
-    const_for!(i in byte_shift..BYTES => {
-        carry += (lhs.0[i] as u16) << subbyte_shift;
-        lhs.0[i] = (carry & 0x00FF) as u8;
-        carry >>= 8;
-    });
+    // first, apply the whole-limb shift
+    let limb_shift = (rhs >> 6) as usize;
+    // shifted limbs end up below this one, everything above is still zero
+    let bound = if len + limb_shift < LIMBS {
+        len + limb_shift + 1
+    } else {
+        LIMBS
+    };
+    if limb_shift > 0 {
+        const_for!(i in (limb_shift..bound).rev() => lhs.0[i] = lhs.0[i - limb_shift]);
+        const_for!(i in 0..limb_shift => lhs.0[i] = 0);
+    }
+
+    // then, the sublimb shift
+    let sublimb_shift = rhs & 0b11_1111;
+    if sublimb_shift > 0 {
+        const_for!(i in ((limb_shift + 1)..bound).rev() => {
+            lhs.0[i] = (lhs.0[i] << sublimb_shift) | (lhs.0[i - 1] >> (u64::BITS - sublimb_shift));
+        });
+        lhs.0[limb_shift] <<= sublimb_shift;
+    }
+
+    // bits shifted past the upper-most one are lost
+    lhs.0[LIMBS - 1] &= TOP_LIMB_MASK;
+    lhs.recount(bound);
     overflow
 }
 
@@ -137,22 +156,30 @@ pub const fn const_shr_assign(result: &mut u206265, mut rhs: u32) -> bool {
         overflow = false;
     }
 
-    // first, apply the whole-byte shift
-    let mut byte_shift = (rhs >> 3) as usize;
-    if byte_shift > 0 {
-        const_for!(i in 0..(BYTES - byte_shift) => result.0[i] = result.0[i + byte_shift]);
-        const_for!(i in (BYTES - byte_shift)..BYTES => result.0[i] = 0);
-        byte_shift -= 1;
+    let len = result.significant_limbs();
+
+    // first, apply the whole-limb shift
+    let limb_shift = (rhs >> 6) as usize;
+    if limb_shift >= len {
+        limbs::zero(limbs::range_mut(&mut result.0, 0, len));
+        result.recount(0);
+        return overflow;
+    }
+    let live_limbs = len - limb_shift;
+    if limb_shift > 0 {
+        const_for!(i in 0..live_limbs => result.0[i] = result.0[i + limb_shift]);
+        const_for!(i in live_limbs..len => result.0[i] = 0);
     }
 
-    // then, the subbyte shift
-    let subbyte_shift = rhs & 0b111;
-    let mut carry = 0u16;
-    const_for!(i in (0..(BYTES - byte_shift)).rev() => {
-        carry += ((result.0[i] as u16) << 8) >> subbyte_shift;
-        result.0[i] = (carry >> 8) as u8;
-        carry <<= 8;
-    });
+    // then, the sublimb shift
+    let sublimb_shift = rhs & 0b11_1111;
+    if sublimb_shift > 0 {
+        const_for!(i in 0..(live_limbs - 1) => {
+            result.0[i] = (result.0[i] >> sublimb_shift) | (result.0[i + 1] << (u64::BITS - sublimb_shift));
+        });
+        result.0[live_limbs - 1] >>= sublimb_shift;
+    }
+    result.recount(live_limbs);
     overflow
 }
 
@@ -175,53 +202,33 @@ pub const fn const_shr(lhs: &u206265, rhs: u32) -> (u206265, bool) {
 pub const fn const_add_assign(lhs: &mut u206265, rhs: &u206265) -> bool {
     let significant_length = {
         let mut sl;
-        let lhs = lhs.significant_bytes();
-        let rhs = rhs.significant_bytes();
+        let lhs = lhs.significant_limbs();
+        let rhs = rhs.significant_limbs();
         if lhs > rhs {
             sl = lhs;
         } else {
             sl = rhs;
         }
-        if sl < BYTES {
+        if sl < LIMBS {
             sl += 1;
         }
         sl
     };
 
-    let mut carry = 0u8;
+    let mut carry = false;
     const_for!(i in 0..significant_length => {
-        let sum = carry;
-        carry = 0;
-
-        let (sum, overflow) = sum.overflowing_add(lhs.0[i]);
-        if overflow {
-            carry += 1;
-        }
-
-        let (sum, overflow) = sum.overflowing_add(rhs.0[i]);
-        if overflow {
-            carry += 1;
-        }
-
+        let (sum, overflow_lhs) = lhs.0[i].overflowing_add(rhs.0[i]);
+        let (sum, overflow_carry) = sum.overflowing_add(carry as u64);
+        carry = overflow_lhs || overflow_carry;
         lhs.0[i] = sum;
     });
 
-    if significant_length == BYTES {
-        match lhs.0[BYTES - 1] {
-            0 | 1 => false,
-            2 => {
-                lhs.0[BYTES - 1] = 0;
-                true
-            }
-            3 => {
-                lhs.0[BYTES - 1] = 1;
-                true
-            }
-            4.. => panic!("Most-significant bit cannot be 4 or more"),
-        }
-    } else {
-        false
-    }
+    // upper-most limbs are too small to carry out of the limb itself
+    debug_assert!(!carry, "Upper-most limb cannot overflow");
+    let overflow = lhs.0[LIMBS - 1] > TOP_LIMB_MASK;
+    lhs.0[LIMBS - 1] &= TOP_LIMB_MASK;
+    lhs.recount(significant_length);
+    overflow
 }
 
 /// Adds ``rhs`` to ``lhs``. Same as [`core::ops::Add::add`], but can be used in constant context.
@@ -239,30 +246,29 @@ pub const fn const_add(lhs: &u206265, rhs: &u206265) -> (u206265, bool) {
 /// ### Returns
 /// If arithmetic underflow had occurred.
 pub const fn const_sub_assign(lhs: &mut u206265, rhs: &u206265) -> bool {
-    let mut borrow = 0u8;
-    const_for!(i in 0..BYTES => {
-        let (sub, underflow) = lhs.0[i].overflowing_sub(borrow);
-
-        borrow = 0;
-        if underflow {
-            borrow += 1;
-        }
-
-        let (sub, underflow) = sub.overflowing_sub(rhs.0[i]);
-        if underflow {
-            borrow += 1;
-        }
+    let significant_length = {
+        let lhs = lhs.significant_limbs();
+        let rhs = rhs.significant_limbs();
+        if lhs > rhs { lhs } else { rhs }
+    };
 
+    let mut borrow = false;
+    const_for!(i in 0..significant_length => {
+        let (sub, underflow_rhs) = lhs.0[i].overflowing_sub(rhs.0[i]);
+        let (sub, underflow_borrow) = sub.overflowing_sub(borrow as u64);
+        borrow = underflow_rhs || underflow_borrow;
         lhs.0[i] = sub;
     });
 
-    match (lhs.0[BYTES - 1] & 0xFE, lhs.0[BYTES - 1] & 0x01) {
-        (0, 0 | 1) => false,
-        (_, last) => {
-            lhs.0[BYTES - 1] = last;
-            true
-        }
+    if borrow {
+        // borrow propagates all the way up, wrapping the value around
+        const_for!(i in significant_length..LIMBS => lhs.0[i] = u64::MAX);
+        lhs.0[LIMBS - 1] &= TOP_LIMB_MASK;
+        lhs.recount(LIMBS);
+    } else {
+        lhs.recount(significant_length);
     }
+    borrow
 }
 
 /// Subtracts ``rhs`` from ``lhs``. Same as [`core::ops::Sub::sub`], but can be used in constant context.
@@ -285,44 +291,220 @@ pub const fn const_mul_assign(lhs: &mut u206265, rhs: &u206265) -> bool {
 }
 
 /// Multiplies ``rhs`` by ``lhs``. Same as [`core::ops::Mul::mul`], but can be used in constant context.
+///
+/// Small operands are multiplied with the schoolbook algorithm, larger ones switch to Karatsuba and Toom-3 algorithms.
+/// Once both operands are large, multiplication is done via number-theoretic transform.
 pub const fn const_mul(lhs: &u206265, rhs: &u206265) -> (u206265, bool) {
-    let lhs_bytes = lhs.significant_bytes();
-    let rhs_bytes = rhs.significant_bytes();
-    let max_power = {
-        let mut mp = lhs_bytes + rhs_bytes + 2;
-        if mp > BYTES {
-            mp = BYTES;
+    let (Some(lhs_log), Some(rhs_log)) = (const_ilog2(lhs), const_ilog2(rhs)) else {
+        return (u206265::ZERO, false);
+    };
+    let lhs_limbs = lhs.limbs();
+    let rhs_limbs = rhs.limbs();
+
+    // limbs landing past the upper-most one are simply dropped
+    let mut result = [0u64; LIMBS];
+    mul_limbs(&mut result, lhs_limbs, rhs_limbs);
+    wrap_product(result, lhs_log + rhs_log, lhs_limbs.len() + rhs_limbs.len())
+}
+
+/// Same as [`mul::mul_truncated`], but with scratch of its own.
+///
+/// Scratch is only as large as the picked algorithm needs, since zeroing the one for transform multiplication takes a while.
+pub(crate) const fn mul_limbs(out: &mut [u64; LIMBS], lhs: &[u64], rhs: &[u64]) {
+    let scratch_len = mul::truncated_scratch_len(lhs.len(), rhs.len());
+    if scratch_len == 0 {
+        mul::mul_truncated(out, lhs, rhs, &mut []);
+    } else if scratch_len <= mul::TRUNCATED_SCRATCH_LEN {
+        mul_scratched::<{ mul::TRUNCATED_SCRATCH_LEN }>(out, lhs, rhs);
+    } else {
+        mul_scratched::<{ ntt::SCRATCH_LEN }>(out, lhs, rhs);
+    }
+}
+
+/// Same as [`mul::mul_truncated`], with `N` limbs of scratch. Scratch of different sizes ends up in different stack frames this way.
+const fn mul_scratched<const N: usize>(out: &mut [u64; LIMBS], lhs: &[u64], rhs: &[u64]) {
+    let mut scratch = [0u64; N];
+    mul::mul_truncated(out, lhs, rhs, &mut scratch);
+}
+
+/// Same as [`mul::sqr_truncated`], but with scratch of its own, see [`mul_limbs`].
+pub(crate) const fn sqr_limbs(out: &mut [u64; LIMBS], value: &[u64]) {
+    let scratch_len = mul::sqr_truncated_scratch_len(value.len());
+    if scratch_len == 0 {
+        mul::sqr_truncated(out, value, &mut []);
+    } else if scratch_len <= mul::TRUNCATED_SCRATCH_LEN {
+        sqr_scratched::<{ mul::TRUNCATED_SCRATCH_LEN }>(out, value);
+    } else {
+        sqr_scratched::<{ ntt::SCRATCH_LEN }>(out, value);
+    }
+}
+
+/// Same as [`mul::sqr_truncated`], with `N` limbs of scratch, see [`mul_scratched`].
+const fn sqr_scratched<const N: usize>(out: &mut [u64; LIMBS], value: &[u64]) {
+    let mut scratch = [0u64; N];
+    mul::sqr_truncated(out, value, &mut scratch);
+}
+
+/// Wraps lower limbs of a product of numbers with logarithms summing to ``log``, detecting the overflow. Only limbs below ``bound`` are looked at.
+pub(crate) const fn wrap_product(
+    mut result: [u64; LIMBS],
+    log: u32,
+    bound: usize,
+) -> (u206265, bool) {
+    // product is at least 2^log and less than 2^(log + 2),
+    // so either overflow is guaranteed, or the whole product fits into the limbs
+    let overflow = log as usize >= BITS || result[LIMBS - 1] > TOP_LIMB_MASK;
+    result[LIMBS - 1] &= TOP_LIMB_MASK;
+    let bound = if bound < LIMBS { bound } else { LIMBS };
+    (u206265::from_limbs_bounded(result, bound), overflow)
+}
+
+/// Squares ``val``, assigning the result. Same as ``const_mul_assign(val, &val.const_clone())``, but faster.
+///
+/// ### Returns
+/// If arithmetic overflow had occurred.
+#[inline]
+pub const fn const_sqr_assign(val: &mut u206265) -> bool {
+    let (result, overflow) = const_sqr(val);
+    *val = result;
+    overflow
+}
+
+/// Squares ``val``. Same as ``const_mul(val, val)``, but faster.
+///
+/// Products of different limbs come in pairs, so only about a half of them is actually computed.
+/// Larger operands are split the same way [`const_mul`] does it, and only need one transform instead of two.
+pub const fn const_sqr(val: &u206265) -> (u206265, bool) {
+    let Some(log) = const_ilog2(val) else {
+        return (u206265::ZERO, false);
+    };
+
+    // limbs landing past the upper-most one are simply dropped
+    let mut result = [0u64; LIMBS];
+    sqr_limbs(&mut result, val.limbs());
+    wrap_product(result, 2 * log, 2 * val.significant_limbs())
+}
+
+/// Raises ``base`` to the power of ``exp``. Same as ``{int}::overflowing_pow``, but can be used in constant context.
+///
+/// Computed by repeated squaring, so it only takes about $2 \log_2(\text{exp})$ multiplications. Zero to the power of zero is one.
+///
+/// Even bases short-circuit to zero, once the power is known to have all of its bits shifted out.
+#[inline]
+pub const fn const_pow(base: &u206265, exp: u32) -> (u206265, bool) {
+    pow(base, &[exp as u64])
+}
+
+/// Same as [`const_pow`], but the exponent is a [`u206265`] as well.
+///
+/// Anything but zero and one overflows for exponents above [`u32::MAX`]. Even bases short-circuit to zero, same as with [`const_pow`].
+/// Odd bases wrap around to one at some power of two below $2^{206264}$, so they are only raised to the exponent, reduced modulo that power. For most bases, that still takes a while.
+#[inline]
+pub const fn const_pow_u206265(base: &u206265, exp: &u206265) -> (u206265, bool) {
+    pow(base, exp.limbs())
+}
+
+/// Raises ``base`` to the power of ``exp``. Same as ``{int}::checked_pow``, but can be used in constant context.
+///
+/// Unlike [`const_pow`], returns as soon as overflow is guaranteed. In particular, nothing is multiplied at all, if $\lfloor \log_2(\text{base}) \rfloor \cdot \text{exp} \ge 206265$.
+pub const fn const_checked_pow(base: &u206265, mut exp: u32) -> Option<u206265> {
+    let Some(log) = const_ilog2(base) else {
+        return Some(if exp == 0 {
+            u206265::ONE
+        } else {
+            u206265::ZERO
+        });
+    };
+    if log as u64 * exp as u64 >= BITS as u64 {
+        return None;
+    }
+    let mut result = u206265::ONE;
+    let mut square = base.const_clone();
+    while exp > 0 {
+        if exp & 1 == 1 && const_mul_assign(&mut result, &square) {
+            return None;
+        }
+        exp >>= 1;
+        // remaining exponent is not zero, so the square is multiplied into the result at some point
+        if exp > 0 && const_sqr_assign(&mut square) {
+            return None;
+        }
+    }
+    Some(result)
+}
+
+/// Raises ``base`` to the power of ``exp``, given as little-endian limbs.
+const fn pow(base: &u206265, exp: &[u64]) -> (u206265, bool) {
+    let exp = limbs::trim(exp);
+    if exp.is_empty() {
+        return (u206265::ONE, false);
+    }
+    let Some(log) = const_ilog2(base) else {
+        return (u206265::ZERO, false);
+    };
+    if log == 0 {
+        // one to any power is one
+        return (u206265::ONE, false);
+    }
+    // power is divisible by $2^{\text{trailing zeros} \cdot \text{exp}}$
+    let trailing_zeros = base.trailing_zeros() as u64;
+    if trailing_zeros > 0 && (exp.len() > 1 || trailing_zeros * exp[0] >= BITS as u64) {
+        return (u206265::ZERO, true);
+    }
+
+    let mut overflow = false;
+    let mut reduced = [0u64; LIMBS];
+    let exp = if trailing_zeros == 0 {
+        // powers past the order are congruent to one, while being greater than one, so they overflow
+        let order_bits = odd_order_bits(base);
+        let exp_bits =
+            (exp.len() - 1) * LIMB_BITS + (u64::BITS - exp[exp.len() - 1].leading_zeros()) as usize;
+        if exp_bits > order_bits {
+            overflow = true;
+            let len = order_bits.div_ceil(LIMB_BITS);
+            limbs::copy(&mut reduced, limbs::range(exp, 0, len));
+            if !order_bits.is_multiple_of(LIMB_BITS) {
+                reduced[len - 1] &= u64::MAX >> (LIMB_BITS - order_bits % LIMB_BITS);
+            }
+            limbs::trim(limbs::range(&reduced, 0, len))
+        } else {
+            exp
         }
-        mp
+    } else {
+        exp
     };
 
-    let mut result = [0u8; BYTES];
-    let mut carry = 0u32; // about 26k additions max, 256 max addition for each
-    const_for!(power in 0..max_power => {
-        #[allow(clippy::range_plus_one, reason = "const_for! is not compatible with ..= syntax :(")]
-        {const_for!(lhs_power in 0..(power+1) => {
-            if lhs_power > lhs_bytes {
-                continue;
+    let mut result = u206265::ONE;
+    let mut square = base.const_clone();
+    // square has overflowed; that only matters, if it's multiplied into the result
+    let mut square_overflow = false;
+    const_for!(i in 0..exp.len() => {
+        let mut bits = exp[i];
+        let last = i == exp.len() - 1;
+        // lower limbs take all of their bits, the upper-most one only goes up to its highest bit
+        let mut steps = if last { u64::BITS - bits.leading_zeros() } else { u64::BITS };
+        while steps > 0 {
+            if bits & 1 == 1 {
+                overflow |= const_mul_assign(&mut result, &square) || square_overflow;
             }
-            let rhs_power = power - lhs_power;
-            if rhs_power > rhs_bytes {
-                continue;
+            bits >>= 1;
+            steps -= 1;
+            if steps > 0 || !last {
+                square_overflow |= const_sqr_assign(&mut square);
             }
-            carry += lhs.0[lhs_power] as u32 * rhs.0[rhs_power] as u32;
-        });}
-        result[power] = (carry & 0xFF) as u8;
-        carry >>= 8;
+        }
     });
+    (result, overflow)
+}
 
-    let overflow;
-    if max_power == BYTES {
-        let last_byte = &mut result[BYTES - 1];
-        overflow = *last_byte > 1u8;
-        *last_byte &= 0x01;
-    } else {
-        overflow = false;
-    }
-    (u206265(result), overflow)
+/// Finds $k$, such that order of the odd ``base`` modulo $2^{206265}$ divides $2^k$, i.e. $\text{base}^{2^k} \equiv 1$.
+///
+/// That's $206266 - v_2(\text{base}^2 - 1)$, but at least one. ``base`` should be odd, and greater than one.
+const fn odd_order_bits(base: &u206265) -> usize {
+    // base^2 - 1 = (base - 1) (base + 1), and trailing ones of the base are the trailing zeros of base + 1
+    let (below, _) = const_sub(base, &u206265::ONE);
+    let twos = below.trailing_zeros() as usize + base.trailing_ones() as usize;
+    if twos >= BITS { 1 } else { BITS + 1 - twos }
 }
 
 /// Divides ``lhs`` by ``rhs``.
@@ -330,27 +512,25 @@ pub const fn const_mul(lhs: &u206265, rhs: &u206265) -> (u206265, bool) {
 /// ### Returns
 /// ``Option<(quotient, remainder)>``. [`Option::None`] corresponds to ``rhs == 0``.
 pub const fn const_div_rem(lhs: &u206265, rhs: &u206265) -> Option<(u206265, u206265)> {
-    if const_cmp(rhs, &u206265::ZERO).is_eq() {
+    let lhs_len = lhs.significant_limbs();
+    let rhs_len = rhs.significant_limbs();
+    if rhs_len == 0 {
         return None;
     }
-    let mut remainder = lhs.const_clone();
-    let mut result = u206265::ZERO;
-    #[allow(clippy::cast_possible_truncation)]
-    let significant_bits = ((lhs
-        .significant_bytes()
-        .saturating_sub(rhs.significant_bytes())
-        + 2)
-        * 8) as u32;
-    const_for!(bit in (0..significant_bits).rev() => {
-        let (probe, _) = const_shl(rhs, bit);
-        if const_cmp(&remainder, &probe).is_lt() {
-            continue;
-        }
-        remainder = const_sub(&remainder, &probe).0;
-        let res_add = const_shl(&u206265::ONE, bit).0;
-        result = const_add(&result, &res_add).0;
-    });
-    Some((result, remainder))
+    let mut quotient = [0u64; LIMBS];
+    let mut remainder = [0u64; LIMBS];
+    let mut scratch = [0u64; div::SCRATCH_LEN];
+    div::div_rem(
+        &mut quotient,
+        &mut remainder,
+        lhs.limbs(),
+        rhs.limbs(),
+        &mut scratch,
+    );
+    Some((
+        u206265::from_limbs_bounded(quotient, (lhs_len + 1).saturating_sub(rhs_len)),
+        u206265::from_limbs_bounded(remainder, rhs_len),
+    ))
 }
 
 /// Divides ``lhs`` by ``rhs``. Same as ``{int}::checked_div``.
@@ -395,6 +575,85 @@ pub const fn const_rem_assign(lhs: &mut u206265, rhs: &u206265) {
     *lhs = const_rem(lhs, rhs).expect("Division by zero");
 }
 
+/// Multiplies ``lhs`` by a machine word ``rhs``, assigning the result. Same as [`const_mul_assign`], but done in a single pass over the limbs.
+///
+/// ### Returns
+/// If arithmetic overflow had occurred.
+pub const fn const_mul_u64_assign(lhs: &mut u206265, rhs: u64) -> bool {
+    let len = lhs.significant_limbs();
+    let carry = limbs::mul_limb_assign(limbs::range_mut(&mut lhs.0, 0, len), rhs);
+    let mut overflow = false;
+    let mut bound = len;
+    if len < LIMBS {
+        lhs.0[len] = carry;
+        bound += 1;
+    } else {
+        overflow = carry > 0;
+    }
+    overflow |= lhs.0[LIMBS - 1] > TOP_LIMB_MASK;
+    lhs.0[LIMBS - 1] &= TOP_LIMB_MASK;
+    lhs.recount(bound);
+    overflow
+}
+
+/// Multiplies ``lhs`` by a machine word ``rhs``. Same as [`const_mul`], but done in a single pass over the limbs.
+#[inline]
+pub const fn const_mul_u64(lhs: &u206265, rhs: u64) -> (u206265, bool) {
+    let mut result = lhs.const_clone();
+    let overflow = const_mul_u64_assign(&mut result, rhs);
+    (result, overflow)
+}
+
+/// Divides ``lhs`` by a machine word ``rhs``. Same as [`const_div_rem`], but done in a single pass over the limbs.
+///
+/// ### Returns
+/// ``Option<(quotient, remainder)>``. [`Option::None`] corresponds to ``rhs == 0``.
+pub const fn const_div_rem_u64(lhs: &u206265, rhs: u64) -> Option<(u206265, u64)> {
+    if rhs == 0 {
+        return None;
+    }
+    let mut quotient = lhs.const_clone();
+    let len = lhs.significant_limbs();
+    let remainder = limbs::div_rem_limb_assign(limbs::range_mut(&mut quotient.0, 0, len), rhs);
+    quotient.recount(len);
+    Some((quotient, remainder))
+}
+
+/// Divides ``lhs`` by a machine word ``rhs``. Same as [`const_div`], but done in a single pass over the limbs.
+#[inline]
+pub const fn const_div_u64(lhs: &u206265, rhs: u64) -> Option<u206265> {
+    if let Some((result, _)) = const_div_rem_u64(lhs, rhs) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Divides ``lhs`` by a machine word ``rhs``, assigning the result. Same as [`const_div_assign`], but done in a single pass over the limbs.
+///
+/// ### Returns
+/// The remainder.
+///
+/// ### Panics
+/// If ``rhs == 0``.
+#[inline]
+pub const fn const_div_u64_assign(lhs: &mut u206265, rhs: u64) -> u64 {
+    assert!(rhs != 0, "Division by zero");
+    let len = lhs.significant_limbs();
+    let remainder = limbs::div_rem_limb_assign(limbs::range_mut(&mut lhs.0, 0, len), rhs);
+    lhs.recount(len);
+    remainder
+}
+
+/// Finds ``lhs`` modulo a machine word ``rhs``. Same as [`const_rem`], but done in a single pass over the limbs, without computing the quotient.
+#[inline]
+pub const fn const_rem_u64(lhs: &u206265, rhs: u64) -> Option<u64> {
+    if rhs == 0 {
+        return None;
+    }
+    Some(limbs::LimbDivisor::new(rhs).rem(lhs.limbs()))
+}
+
 /// Finds $\log_{\text{base}}(\test{val})$, if one exists. Same as ``{int}::checked_ilog``.
 ///
 /// This implementation attempts to be consistent with ``core`` functions, so please check "logarithms exists" means exactly what you think it means. For instance, in ``core`` terms, ``logi(1, 1)`` does not exist:
@@ -431,7 +690,7 @@ pub const fn const_ilog(val: &u206265, base: &u206265) -> Option<u32> {
             let new_probe = if let Some(present) = new_probe {
                 present
             } else {
-                *new_probe = Some(const_mul(&probe.0, &probe.0));
+                *new_probe = Some(const_sqr(&probe.0));
                 new_probe.as_ref().unwrap()
             };
             if new_probe.1 || const_cmp(&val, &new_probe.0).is_lt() {
@@ -457,15 +716,154 @@ pub const fn const_ilog10(val: &u206265) -> Option<u32> {
 /// MUCH faster than ``const_ilog(_, 2)``, so prefer this one, if you know your base to be 2.
 pub const fn const_ilog2(val: &u206265) -> Option<u32> {
     // basically, I need to find position of the highest bit
-    let high_byte_pos = val.significant_bytes();
-    let high_byte = val.0[high_byte_pos - 1]; // 1 significant byte means highest byte is 0, an so on
-    let Some(high_byte_bit) = high_byte.checked_ilog2() else {
-        // if high byte is 0, the number itself is 0 - return none
-        //
-        // const context - can't use the `?` operator :(
+    let high_limb_pos = val.significant_limbs();
+    if high_limb_pos == 0 {
+        // no significant limbs means the number itself is 0 - return none
         return None;
+    }
+    let high_limb_bit = val.0[high_limb_pos - 1].ilog2();
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "BITS_U32 exists, and limb count is less than number of bits - it's safe to cast"
+    )]
+    let high_limb_pos = high_limb_pos as u32;
+    Some((high_limb_pos - 1) * u64::BITS + high_limb_bit)
+}
+
+/// Square roots with at most this many bits are found right away, with [`u128::isqrt`].
+const SQRT_DIRECT_BITS: u32 = 64;
+
+/// Finds the square root of ``val``, rounded down, along with the remainder ``val - root * root``.
+///
+/// Root of the upper bits of ``val`` is the upper bits of the root, so the root is found with precision doubling: root of the upper 128 bits is found right away, and then each time twice as many bits are taken, with the previous root (scaled up) being the estimate for Newton's iteration.
+/// Estimate already has half of the bits right, so the iteration converges in a couple of steps, and only the last of these steps are done at the full precision.
+pub const fn const_sqrt_rem(val: &u206265) -> (u206265, u206265) {
+    let Some(log) = const_ilog2(val) else {
+        return (u206265::ZERO, u206265::ZERO);
     };
-    Some((val.significant_bytes_u32() - 1) * 8 + high_byte_bit)
+    // val < 2^(log + 1), so the root is below 2^(log / 2 + 1)
+    let root_bits = log / 2 + 1;
+    // root of val >> (2 * shifts[i]) has about root_bits / 2^i bits
+    let mut shifts = [0u32; u32::BITS as usize];
+    let mut levels = 0;
+    let mut bits = root_bits;
+    while bits > SQRT_DIRECT_BITS {
+        bits = bits.div_ceil(2);
+        levels += 1;
+        shifts[levels] = root_bits - bits;
+    }
+
+    // the upper part is below 2^(2 * bits), so it fits into u128
+    let (upper, _) = const_shr(val, 2 * shifts[levels]);
+    let upper = upper.0[0] as u128 | (upper.0[1] as u128) << u64::BITS;
+    let mut root = u206265::from_u128(upper.isqrt());
+    while levels > 0 {
+        levels -= 1;
+        let (part, _) = const_shr(val, 2 * shifts[levels]);
+        // if r = isqrt(m), then (r + 1)^2 > m, so (r + 1) * 2^d is above the root of anything below (m + 1) * 4^d
+        const_add_assign(&mut root, &u206265::ONE);
+        const_shl_assign(&mut root, shifts[levels + 1] - shifts[levels]);
+        root = newton_sqrt(&part, root);
+    }
+    let (square, _) = const_sqr(&root);
+    let (remainder, _) = const_sub(val, &square);
+    (root, remainder)
+}
+
+/// Finds the square root of ``val``, rounded down, with Newton's iteration. ``estimate`` should be at least the root; estimates then decrease monotonically, until they reach the root.
+const fn newton_sqrt(val: &u206265, mut estimate: u206265) -> u206265 {
+    loop {
+        let quotient = const_div(val, &estimate).expect("Estimate is never zero");
+        // estimate is way below the overflow, and so is the sum
+        let (mut next, _) = const_add(&estimate, &quotient);
+        const_shr_assign(&mut next, 1);
+        if const_cmp(&next, &estimate).is_ge() {
+            return estimate;
+        }
+        estimate = next;
+    }
+}
+
+/// Finds the square root of ``val``, rounded down. Same as ``{int}::isqrt``, but can be used in constant context.
+#[inline]
+pub const fn const_isqrt(val: &u206265) -> u206265 {
+    const_sqrt_rem(val).0
+}
+
+/// Same as [`const_isqrt`]. Square root of an unsigned integer always exists, so this never returns [`None`]; it is only here to match ``{int}::checked_isqrt`` of the signed primitives.
+#[inline]
+pub const fn const_checked_isqrt(val: &u206265) -> Option<u206265> {
+    Some(const_isqrt(val))
+}
+
+/// Number of upper bits of a large root, found bit by bit before switching to Newton's iteration in [`const_iroot_rem`].
+///
+/// Finding more bits is slower for large exponents, and finding less needs more iterations.
+const ESTIMATE_BITS: u32 = 32;
+
+/// Finds the ``n``-th root of ``val``, rounded down, along with the remainder ``val - root^n``.
+///
+/// Roots fitting into a single limb are found bit by bit, from the upper-most one, bounded with [`const_ilog2`].
+/// Larger ones are found with Newton's iteration, same as in [`const_sqrt_rem`], starting from the root of the upper bits of ``val``. This estimate already has 32 correct bits, so Newton's iteration only has to double them a couple of times.
+///
+/// ### Returns
+/// ``Option<(root, remainder)>``. [`Option::None`] corresponds to ``n == 0``.
+pub const fn const_iroot_rem(val: &u206265, n: u32) -> Option<(u206265, u206265)> {
+    if n == 0 {
+        return None;
+    }
+    let Some(log) = const_ilog2(val) else {
+        return Some((u206265::ZERO, u206265::ZERO));
+    };
+    if n == 1 {
+        return Some((val.const_clone(), u206265::ZERO));
+    }
+    // val < 2^(log + 1), so the root is below 2^(log / n + 1)
+    let root_bits = log / n + 1;
+    let root = if root_bits <= u64::BITS {
+        u206265::from_u64(iroot_bits(val, n, root_bits))
+    } else {
+        // root of the upper bits is the upper bits of the root, up to one; adding one keeps the estimate above the root
+        let shift = root_bits - ESTIMATE_BITS;
+        let (upper, _) = const_shr(val, shift * n);
+        let estimate = iroot_bits(&upper, n, ESTIMATE_BITS) as u128 + 1;
+        let (mut root, _) = const_shl(&u206265::from_u128(estimate), shift);
+        loop {
+            // powers past the overflow are greater than val anyway
+            let quotient = match const_checked_pow(&root, n - 1) {
+                Some(power) => const_div(val, &power).expect("Estimate is never zero"),
+                None => u206265::ZERO,
+            };
+            // estimate is small, so none of these overflow
+            let (mut next, _) = const_mul_u64(&root, n as u64 - 1);
+            const_add_assign(&mut next, &quotient);
+            const_div_u64_assign(&mut next, n as u64);
+            if const_cmp(&next, &root).is_ge() {
+                break;
+            }
+            root = next;
+        }
+        root
+    };
+    let (power, _) = const_pow(&root, n);
+    let (remainder, _) = const_sub(val, &power);
+    Some((root, remainder))
+}
+
+/// Finds the ``n``-th root of ``val``, rounded down, one bit at a time. Root should be below $2^\text{root_bits}$, and fit into a limb.
+const fn iroot_bits(val: &u206265, n: u32, root_bits: u32) -> u64 {
+    let mut root = 0u64;
+    let mut bit = root_bits;
+    while bit > 0 {
+        bit -= 1;
+        let candidate = root | (1 << bit);
+        if let Some(power) = const_checked_pow(&u206265::from_u64(candidate), n)
+            && const_cmp(&power, val).is_le()
+        {
+            root = candidate;
+        }
+    }
+    root
 }
 
 macro_rules! bit_op {
@@ -475,16 +873,17 @@ macro_rules! bit_op {
             #[doc = concat!("Same as [`core::ops::", stringify!([<$op_name Assign>]), "::", stringify!([<$op_name:lower _assign>]), "`], but can be used in a constant context.")]
             #[doc = concat!("Operation is performed in-place, so prefer using this function (or ``", stringify!($op_assign), "`` operator), if you wish to avoid copying [`u206265`]s around.")]
             pub const fn [<const_ $op_name:lower _assign>](lhs: &mut u206265, rhs: &u206265) {
-                let lhs_bytes = lhs.significant_bytes();
-                let rhs_bytes = rhs.significant_bytes();
-                let bytes = if lhs_bytes >= rhs_bytes {
-                    lhs_bytes
+                let lhs_limbs = lhs.significant_limbs();
+                let rhs_limbs = rhs.significant_limbs();
+                let limbs = if lhs_limbs >= rhs_limbs {
+                    lhs_limbs
                 } else {
-                    rhs_bytes
+                    rhs_limbs
                 };
-                const_for!(i in 0..bytes => {
+                const_for!(i in 0..limbs => {
                     lhs.0[i] $op_assign rhs.0[i];
                 });
+                lhs.recount(limbs);
             }
 
             #[doc = concat!("Finds ", stringify!([<$op_name:lower>]), " of ``lhs`` and ``rhs``.")]
@@ -509,9 +908,10 @@ bit_op! {BitXor, ^=}
 ///
 /// NOTE: ``!x`` COPIES the value, see [`core::ops::Not::not`] signature.
 pub const fn const_not_assign(val: &mut u206265) {
-    const_for!(i in 0..BYTES => {
+    const_for!(i in 0..LIMBS => {
         val.0[i] = !val.0[i];
     });
-    // last byte should only use one bit
-    val.0[BYTES - 1] &= 0x01;
+    // last limb should only use some of the bits
+    val.0[LIMBS - 1] &= TOP_LIMB_MASK;
+    val.recount(LIMBS);
 }
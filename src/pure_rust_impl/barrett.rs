@@ -0,0 +1,179 @@
+//! Division by a precomputed reciprocal (Barrett reduction).
+//!
+//! For a divisor $d$ of $n$ limbs, reciprocal $R = \lfloor B^L / d \rfloor$ is found once, with Newton's iteration. Here $B = 2^{64}$, and $L$ is [`INVERSE_LEN`].
+//! Then, quotient is found $n$ limbs at a time: each step estimates $n$ quotient limbs with one multiplication by the upper limbs of $R$, and checks them with another one.
+
+use super::{
+    div,
+    limbs::{self, range, range_mut},
+    mul,
+};
+use crate::LIMBS;
+
+/// Power of $B$ reciprocal is taken of. It's one limb more than a [`u206265`](crate::u206265) has, so that any number fits into the steps of [`div_rem`].
+pub(crate) const INVERSE_LEN: usize = LIMBS + 1;
+
+/// Reciprocal is at most this many limbs long.
+///
+/// Divisors are at least two limbs long, so reciprocal actually fits into [`LIMBS`], but the Newton's iteration needs a spare limb.
+pub(crate) const RECIPROCAL_LEN: usize = LIMBS + 1;
+
+/// Divisor should be at least this many limbs long for Barrett reduction to be faster than [`div::div_rem`].
+pub(crate) const DIVISOR_THRESHOLD: usize = 16;
+
+/// Quotient should be at least this many limbs long for Barrett reduction to be faster than [`div::div_rem`].
+pub(crate) const QUOTIENT_THRESHOLD: usize = 100;
+
+/// Precision (in limbs) Newton's iteration starts from, found with a long division.
+const NEWTON_THRESHOLD: usize = 16;
+
+/// Scratch [`mul::mul_trimmed`] needs for any of the multiplications below.
+const MUL_SCRATCH_LEN: usize = mul::scratch_len(LIMBS + 2);
+
+/// Number of scratch limbs [`inverse`] needs.
+pub(crate) const INVERSE_SCRATCH_LEN: usize = 6 * LIMBS + MUL_SCRATCH_LEN;
+
+/// Number of scratch limbs [`div_rem`] needs.
+pub(crate) const DIV_SCRATCH_LEN: usize = 4 * LIMBS + MUL_SCRATCH_LEN;
+
+/// Finds $\lfloor B^L / d \rfloor$, writing it into `out`, which should be [`RECIPROCAL_LEN`] limbs long.
+///
+/// ### Panics
+/// If `divisor` is shorter than two limbs.
+pub(crate) const fn inverse(out: &mut [u64], divisor: &[u64], scratch: &mut [u64]) {
+    let divisor = limbs::trim(divisor);
+    assert!(divisor.len() >= 2, "Divisor is too short");
+    let precision = INVERSE_LEN - divisor.len();
+    approximate(
+        range_mut(out, 0, precision + 2),
+        divisor,
+        precision,
+        scratch,
+    );
+
+    // approximation is at most one less than the actual value
+    let (product, scratch) = scratch.split_at_mut(INVERSE_LEN + 2);
+    mul::mul_trimmed(product, divisor, out, scratch);
+    let remainder = range_mut(product, 0, INVERSE_LEN);
+    limbs::neg_assign(remainder);
+    while limbs::cmp(remainder, divisor).is_ge() {
+        limbs::sub_assign(remainder, divisor);
+        limbs::add_assign(out, &[1]);
+    }
+}
+
+/// Approximates $\lfloor B^{n + p} / d \rfloor$ from below, where $n$ is the length of `divisor`, and $p$ is `precision`.
+///
+/// Result is at most one less than the actual value. `out` should be `precision + 2` limbs long.
+const fn approximate(out: &mut [u64], divisor: &[u64], precision: usize, scratch: &mut [u64]) {
+    let len = divisor.len();
+    if precision <= NEWTON_THRESHOLD {
+        let (numerator, scratch) = scratch.split_at_mut(len + precision + 1);
+        limbs::zero(numerator);
+        numerator[len + precision] = 1;
+        let (remainder, scratch) = scratch.split_at_mut(len);
+        div::div_rem(out, remainder, numerator, divisor, scratch);
+        return;
+    }
+
+    // Newton's step doubles the precision, minus a limb to keep the error from growing
+    let half = (precision + 2) / 2;
+    let (previous, scratch) = scratch.split_at_mut(half + 2);
+    approximate(previous, divisor, half, scratch);
+
+    // error = B^(n + h) - d * previous, which is non-negative and less than 2d
+    let (error, scratch) = scratch.split_at_mut(len + half + 2);
+    mul::mul_trimmed(error, divisor, previous, scratch);
+    let error = range_mut(error, 0, len + half);
+    limbs::neg_assign(error);
+    let error = limbs::trim(error);
+
+    // out = previous * B^(p - h) + previous * error / B^(n + 2h - p)
+    let (correction, scratch) = scratch.split_at_mut(len + 2 * half + 4);
+    mul::mul_trimmed(correction, previous, error, scratch);
+    let shift = precision - half;
+    limbs::zero(out);
+    limbs::copy(range_mut(out, shift, precision + 2), previous);
+    let carry = limbs::add_assign(
+        out,
+        range(correction, len + 2 * half - precision, correction.len()),
+    );
+    debug_assert!(!carry, "Approximation cannot exceed its limbs");
+}
+
+/// Divides `lhs` by `divisor`, using its reciprocal from [`inverse`]. Writes the quotient and the remainder (zero-padded) into respective slices.
+///
+/// `lhs` should not be longer than [`LIMBS`], and `divisor` should be at least two limbs long.
+pub(crate) const fn div_rem(
+    quotient: &mut [u64],
+    remainder: &mut [u64],
+    lhs: &[u64],
+    divisor: &[u64],
+    reciprocal: &[u64],
+    scratch: &mut [u64],
+) {
+    let lhs = limbs::trim(lhs);
+    let divisor = limbs::trim(divisor);
+    let reciprocal = limbs::trim(reciprocal);
+    debug_assert!(lhs.len() <= LIMBS && divisor.len() >= 2);
+    limbs::zero(quotient);
+    if limbs::cmp(lhs, divisor).is_lt() {
+        limbs::copy(remainder, lhs);
+        return;
+    }
+
+    let len = divisor.len();
+    let quotient_len = lhs.len() - len + 1;
+    // remainder of each step is left in place of its window
+    let (work, scratch) = scratch.split_at_mut(lhs.len() + 1);
+    limbs::copy(work, lhs);
+    let mut end = quotient_len;
+    while end > 0 {
+        let step = if end < len { end } else { len };
+        let start = end - step;
+        let window = range_mut(work, start, end + len);
+        div_rem_step(
+            range_mut(quotient, start, end),
+            window,
+            divisor,
+            reciprocal,
+            scratch,
+        );
+        end = start;
+    }
+    limbs::copy(remainder, range(work, 0, len));
+}
+
+/// Divides `window` by `divisor`, leaving remainder in its place. Window should be $n + k$ limbs long and less than $d B^k$, with quotient being `k` limbs long.
+const fn div_rem_step(
+    quotient: &mut [u64],
+    window: &mut [u64],
+    divisor: &[u64],
+    reciprocal: &[u64],
+    scratch: &mut [u64],
+) {
+    let len = divisor.len();
+    let step = quotient.len();
+    // floor(B^(n + k) / d) is just upper limbs of the reciprocal
+    let inverse = range(reciprocal, INVERSE_LEN - len - step, reciprocal.len());
+    // lower limbs of the window affect the estimate by less than one
+    let (product, scratch) = scratch.split_at_mut(2 * step + 3);
+    mul::mul_trimmed(
+        product,
+        range(window, len - 2, window.len()),
+        inverse,
+        scratch,
+    );
+    let estimate = limbs::trim(range(product, step + 2, product.len()));
+    limbs::copy(quotient, estimate);
+
+    // estimate is at most two less than the actual quotient
+    let (check, scratch) = scratch.split_at_mut(len + step);
+    mul::mul_trimmed(check, quotient, divisor, scratch);
+    let borrow = limbs::sub_assign(window, check);
+    debug_assert!(!borrow, "Estimate cannot exceed the quotient");
+    while limbs::cmp(window, divisor).is_ge() {
+        limbs::sub_assign(window, divisor);
+        limbs::add_assign(quotient, &[1]);
+    }
+}
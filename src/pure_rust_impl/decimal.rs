@@ -0,0 +1,130 @@
+//! Divide-and-conquer conversion into decimal.
+//!
+//! Number is split by $10^{38 \cdot 2^k}$ into two halves, which are then converted (and split) independently. This way, most of the work is done by a few big divisions, instead of a couple of thousand full-length divisions by $10^{38}$.
+
+use const_for::const_for;
+
+use super::{
+    div,
+    limbs::{self, range, range_mut},
+    sqr_limbs,
+};
+use crate::{LIMB_BITS, LIMBS};
+
+/// Number of decimal digits per chunk. $10^{38}$ is the largest power of ten fitting into [`u128`].
+const CHUNK_DIGITS: usize = 38;
+
+/// Number of chunks [`to_chunks`] writes. It's a power of two, and is enough to fit [`u206265::MAX`](crate::u206265::MAX), having 62092 digits.
+pub(crate) const CHUNKS: usize = 1 << LEVELS;
+
+/// Chunks are split in halves this many times.
+const LEVELS: usize = 11;
+
+/// $10^{19}$, largest power of ten fitting into a limb.
+const LIMB_TEN_POWER: u64 = 10u64.pow(19);
+
+/// Numbers of at most this many limbs are converted with repeated single-limb divisions.
+const BASE_LIMBS: usize = 4;
+
+/// Power $10^{38 \cdot 2^k}$ takes at most $2^{k+1}$ limbs, so that's the size of a slot for it.
+const fn slot(level: usize) -> (usize, usize) {
+    let start = (1 << (level + 1)) - 2;
+    (start, start + (1 << (level + 1)))
+}
+
+/// Total length of power slots.
+pub(crate) const POWERS_LEN: usize = slot(LEVELS - 1).1;
+
+/// Limbs [`convert`] needs to keep quotients and remainders at all levels of recursion, plus the scratch for the divisions.
+pub(crate) const SCRATCH_LEN: usize = 3 * LIMBS + div::SCRATCH_LEN;
+
+/// Number of chunks `value` is split into. It's a power of two, so that chunks can be split in halves all the way down.
+pub(crate) const fn chunk_count(value: &[u64]) -> usize {
+    // 10^38 > 2^126, so each chunk holds at least 126 bits
+    (limbs::significant_len(value) * LIMB_BITS)
+        .div_ceil(126)
+        .next_power_of_two()
+}
+
+/// Computes powers $10^{38 \cdot 2^k}$ into their slots, only the ones needed to split `chunks` in halves.
+pub(crate) const fn powers(powers: &mut [u64; POWERS_LEN], chunks: usize) {
+    let levels = chunks.trailing_zeros() as usize;
+    let ten_38 = 10u128.pow(CHUNK_DIGITS as u32);
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "splitting into limbs on purpose"
+    )]
+    {
+        powers[0] = ten_38 as u64;
+        powers[1] = (ten_38 >> u64::BITS) as u64;
+    }
+    let mut square = [0u64; LIMBS];
+    const_for!(level in 1..levels => {
+        let (previous_start, previous_end) = slot(level - 1);
+        let previous = limbs::trim(range(powers, previous_start, previous_end));
+        sqr_limbs(&mut square, previous);
+        let (start, end) = slot(level);
+        limbs::copy(range_mut(powers, start, end), range(&square, 0, end - start));
+    });
+}
+
+/// Power, splitting `2 * half` chunks in halves.
+pub(crate) const fn split_power(powers: &[u64], half: usize) -> &[u64] {
+    let (start, end) = slot(half.trailing_zeros() as usize);
+    limbs::trim(range(powers, start, end))
+}
+
+/// Converts `value` into base $10^{38}$, writing [`CHUNKS`] chunks (zero-padded, lower first) into `out`.
+pub(crate) const fn to_chunks(out: &mut [u128; CHUNKS], value: &[u64]) {
+    let value = limbs::trim(value);
+    let chunks = chunk_count(value);
+    let mut powers = [0u64; POWERS_LEN];
+    self::powers(&mut powers, chunks);
+
+    let mut scratch = [0u64; SCRATCH_LEN];
+    let (used, unused) = out.split_at_mut(chunks);
+    convert(used, value, &powers, &mut scratch);
+    const_for!(i in 0..unused.len() => unused[i] = 0);
+}
+
+/// Converts `value` into `out.len()` chunks. `value` should be less than $10^{38 \cdot \text{out.len()}}$, and `out.len()` should be a power of two.
+///
+/// `scratch` should be at least [`SCRATCH_LEN`] long.
+pub(crate) const fn convert(out: &mut [u128], value: &[u64], powers: &[u64], scratch: &mut [u64]) {
+    let value = limbs::trim(value);
+    if value.len() <= BASE_LIMBS {
+        convert_small(out, value);
+        return;
+    }
+
+    let half = out.len() / 2;
+    let divisor = split_power(powers, half);
+    let quotient_len = (value.len() + 1).saturating_sub(divisor.len());
+    let (quotient, scratch) = scratch.split_at_mut(quotient_len);
+    let (remainder, scratch) = scratch.split_at_mut(divisor.len());
+    div::div_rem(quotient, remainder, value, divisor, scratch);
+
+    let (lower, upper) = out.split_at_mut(half);
+    convert(lower, remainder, powers, scratch);
+    convert(upper, quotient, powers, scratch);
+}
+
+/// Converts `value` into `out.len()` chunks, dividing it by $10^{19}$ over and over.
+const fn convert_small(out: &mut [u128], value: &[u64]) {
+    let mut value = {
+        let mut buf = [0u64; BASE_LIMBS];
+        limbs::copy(&mut buf, value);
+        buf
+    };
+    let mut len = value.len();
+    const_for!(i in 0..out.len() => {
+        len = limbs::significant_len(range(&value, 0, len));
+        if len == 0 {
+            out[i] = 0;
+            continue;
+        }
+        let low = limbs::div_rem_limb_assign(range_mut(&mut value, 0, len), LIMB_TEN_POWER);
+        let high = limbs::div_rem_limb_assign(range_mut(&mut value, 0, len), LIMB_TEN_POWER);
+        out[i] = high as u128 * LIMB_TEN_POWER as u128 + low as u128;
+    });
+}
@@ -0,0 +1,126 @@
+//! Long division of limb slices (Knuth's Algorithm D, TAOCP vol. 2, 4.3.1).
+//!
+//! Each step estimates a whole quotient limb from the upper limbs of the remainder and the divisor. Once the divisor is normalized (has the upper-most bit set), the estimate is off by at most two, and is corrected with a comparison and, very rarely, an add-back.
+
+use const_for::const_for;
+
+use super::limbs::{self, range_mut};
+use crate::{LIMB_BITS, LIMBS};
+
+/// Number of scratch limbs [`div_rem`] needs for operands up to `lhs_len` and `rhs_len` limbs long.
+pub(crate) const fn scratch_len(lhs_len: usize, rhs_len: usize) -> usize {
+    // normalized dividend and divisor
+    lhs_len + 1 + rhs_len
+}
+
+/// Scratch limbs enough for any pair of [`u206265`](crate::u206265)s.
+pub(crate) const SCRATCH_LEN: usize = scratch_len(LIMBS, LIMBS);
+
+/// Divides `lhs` by `rhs`, writing the quotient and the remainder (zero-padded) into respective slices.
+///
+/// `scratch` should be at least [`scratch_len`] limbs long.
+///
+/// ### Panics
+/// - If `rhs` is zero
+/// - If `quotient` or `remainder` are too short to fit the result
+pub(crate) const fn div_rem(
+    quotient: &mut [u64],
+    remainder: &mut [u64],
+    lhs: &[u64],
+    rhs: &[u64],
+    scratch: &mut [u64],
+) {
+    let lhs = limbs::trim(lhs);
+    let rhs = limbs::trim(rhs);
+    assert!(!rhs.is_empty(), "Division by zero");
+
+    if limbs::cmp(lhs, rhs).is_lt() {
+        limbs::zero(quotient);
+        limbs::copy(remainder, lhs);
+        return;
+    }
+
+    if rhs.len() == 1 {
+        // only the live limbs are divided, the rest of the quotient stays zero
+        limbs::zero(quotient);
+        let live = range_mut(quotient, 0, lhs.len());
+        limbs::copy(live, lhs);
+        let rem = limbs::div_rem_limb_assign(live, rhs[0]);
+        limbs::copy(remainder, &[rem]);
+        return;
+    }
+
+    // D1: normalize, so that divisor has the upper-most bit set
+    let divisor_len = rhs.len();
+    let shift = rhs[divisor_len - 1].leading_zeros();
+    let (divisor, scratch) = scratch.split_at_mut(divisor_len);
+    limbs::copy(divisor, rhs);
+    limbs::shl_assign(divisor, shift);
+    // dividend gets an extra limb for the bits shifted out
+    let numerator = range_mut(scratch, 0, lhs.len() + 1);
+    limbs::copy(numerator, lhs);
+    let spill = limbs::shl_assign(range_mut(numerator, 0, lhs.len()), shift);
+    numerator[lhs.len()] = spill;
+
+    limbs::zero(quotient);
+    let divisor_top = divisor[divisor_len - 1] as u128;
+    let divisor_next = divisor[divisor_len - 2] as u128;
+    // D2: loop over the quotient limbs
+    const_for!(j in (0..lhs.len() - divisor_len + 1).rev() => {
+        // D3: estimate quotient limb from the upper two limbs
+        let top = ((numerator[j + divisor_len] as u128) << LIMB_BITS)
+            | numerator[j + divisor_len - 1] as u128;
+        let mut estimate = top / divisor_top;
+        let mut estimate_rem = top % divisor_top;
+        while estimate > u64::MAX as u128
+            || estimate * divisor_next
+                > ((estimate_rem << LIMB_BITS) | numerator[j + divisor_len - 2] as u128)
+        {
+            estimate -= 1;
+            estimate_rem += divisor_top;
+            if estimate_rem > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation, reason = "estimate was corrected to fit into a limb")]
+        let mut estimate = estimate as u64;
+
+        // D4: multiply and subtract
+        let window = range_mut(numerator, j, j + divisor_len + 1);
+        let negative = mul_sub_assign(window, divisor, estimate);
+
+        // D5, D6: estimate was still one too big, add divisor back
+        if negative {
+            estimate -= 1;
+            limbs::add_assign(window, divisor);
+        }
+        quotient[j] = estimate;
+    });
+
+    // D8: unnormalize the remainder
+    let rem = range_mut(numerator, 0, divisor_len);
+    limbs::shr_assign(rem, shift);
+    limbs::copy(remainder, rem);
+}
+
+/// Subtracts `divisor * factor` from `window`. Window is expected to be one limb longer than the divisor.
+///
+/// ### Returns
+/// Whether result is negative. In that case, `window` is left in two's complement form, and adding the `divisor` back will discard the carry.
+const fn mul_sub_assign(window: &mut [u64], divisor: &[u64], factor: u64) -> bool {
+    let mut carry = 0u64;
+    const_for!(i in 0..divisor.len() => {
+        // at most 2^128 - 2^64, so it does fit
+        let product = factor as u128 * divisor[i] as u128 + carry as u128;
+        #[allow(clippy::cast_possible_truncation, reason = "splitting into limbs on purpose")]
+        let (low, high) = (product as u64, (product >> LIMB_BITS) as u64);
+        let (diff, borrow) = window[i].overflowing_sub(low);
+        window[i] = diff;
+        // upper limb can only be 2^64 - 1 if lower one is zero, so that does not overflow either
+        carry = high + borrow as u64;
+    });
+    let top = window[divisor.len()];
+    window[divisor.len()] = top.wrapping_sub(carry);
+    carry > top
+}
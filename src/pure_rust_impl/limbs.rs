@@ -0,0 +1,342 @@
+//! Primitives over little-endian limb slices.
+//!
+//! Faster algorithms need to work on parts of the numbers, so these operate on arbitrary slices instead of the whole [`u206265`](crate::u206265)s.
+
+use core::cmp::Ordering;
+
+use const_for::const_for;
+
+use crate::LIMB_BITS;
+
+/// Same as `&slice[start..end]`, but can be used in constant context.
+#[inline]
+pub(crate) const fn range(slice: &[u64], start: usize, end: usize) -> &[u64] {
+    slice.split_at(end).0.split_at(start).1
+}
+
+/// Same as `&mut slice[start..end]`, but can be used in constant context.
+#[inline]
+pub(crate) const fn range_mut(slice: &mut [u64], start: usize, end: usize) -> &mut [u64] {
+    slice.split_at_mut(end).0.split_at_mut(start).1
+}
+
+/// Number of limbs up to (and including) the highest non-zero one.
+#[inline]
+pub(crate) const fn significant_len(slice: &[u64]) -> usize {
+    let mut len = slice.len();
+    while len > 0 && slice[len - 1] == 0 {
+        len -= 1;
+    }
+    len
+}
+
+/// Strips zero upper limbs off the slice.
+#[inline]
+pub(crate) const fn trim(slice: &[u64]) -> &[u64] {
+    slice.split_at(significant_len(slice)).0
+}
+
+/// Sets all of the limbs to zero.
+#[inline]
+pub(crate) const fn zero(slice: &mut [u64]) {
+    const_for!(i in 0..slice.len() => slice[i] = 0);
+}
+
+/// Copies `src` into lower limbs of `dst`, setting the rest to zero.
+///
+/// ### Panics
+/// If `src` is longer than `dst`.
+#[inline]
+pub(crate) const fn copy(dst: &mut [u64], src: &[u64]) {
+    assert!(src.len() <= dst.len(), "Destination is too short");
+    const_for!(i in 0..src.len() => dst[i] = src[i]);
+    const_for!(i in src.len()..dst.len() => dst[i] = 0);
+}
+
+/// Compares two slices as numbers. Slices are allowed to be of different lengths.
+pub(crate) const fn cmp(lhs: &[u64], rhs: &[u64]) -> Ordering {
+    let lhs = trim(lhs);
+    let rhs = trim(rhs);
+    if lhs.len() < rhs.len() {
+        return Ordering::Less;
+    }
+    if lhs.len() > rhs.len() {
+        return Ordering::Greater;
+    }
+    const_for!(i in (0..lhs.len()).rev() => {
+        if lhs[i] < rhs[i] {
+            return Ordering::Less;
+        }
+        if lhs[i] > rhs[i] {
+            return Ordering::Greater;
+        }
+    });
+    Ordering::Equal
+}
+
+/// Adds `rhs` to `lhs`, propagating the carry through all of the `lhs`.
+///
+/// ### Returns
+/// Carry out of the upper-most limb of `lhs`.
+///
+/// ### Panics
+/// If significant part of `rhs` is longer than `lhs`.
+pub(crate) const fn add_assign(lhs: &mut [u64], rhs: &[u64]) -> bool {
+    let rhs = trim(rhs);
+    assert!(rhs.len() <= lhs.len(), "Addend is too long");
+    let mut carry = false;
+    const_for!(i in 0..rhs.len() => {
+        let (sum, overflow_rhs) = lhs[i].overflowing_add(rhs[i]);
+        let (sum, overflow_carry) = sum.overflowing_add(carry as u64);
+        carry = overflow_rhs || overflow_carry;
+        lhs[i] = sum;
+    });
+    let mut i = rhs.len();
+    while carry && i < lhs.len() {
+        let (sum, overflow) = lhs[i].overflowing_add(1);
+        lhs[i] = sum;
+        carry = overflow;
+        i += 1;
+    }
+    carry
+}
+
+/// Subtracts `rhs` from `lhs`, propagating the borrow through all of the `lhs`.
+///
+/// ### Returns
+/// Borrow out of the upper-most limb of `lhs`.
+///
+/// ### Panics
+/// If significant part of `rhs` is longer than `lhs`.
+pub(crate) const fn sub_assign(lhs: &mut [u64], rhs: &[u64]) -> bool {
+    let rhs = trim(rhs);
+    assert!(rhs.len() <= lhs.len(), "Subtrahend is too long");
+    let mut borrow = false;
+    const_for!(i in 0..rhs.len() => {
+        let (sub, underflow_rhs) = lhs[i].overflowing_sub(rhs[i]);
+        let (sub, underflow_borrow) = sub.overflowing_sub(borrow as u64);
+        borrow = underflow_rhs || underflow_borrow;
+        lhs[i] = sub;
+    });
+    let mut i = rhs.len();
+    while borrow && i < lhs.len() {
+        let (sub, underflow) = lhs[i].overflowing_sub(1);
+        lhs[i] = sub;
+        borrow = underflow;
+        i += 1;
+    }
+    borrow
+}
+
+/// Sets `lhs` to `rhs - lhs`.
+///
+/// ### Panics
+/// If `rhs < lhs`, or if significant part of `rhs` is longer than `lhs`.
+pub(crate) const fn rsub_assign(lhs: &mut [u64], rhs: &[u64]) {
+    let rhs = trim(rhs);
+    assert!(rhs.len() <= lhs.len(), "Minuend is too long");
+    let mut borrow = false;
+    const_for!(i in 0..lhs.len() => {
+        let minuend = if i < rhs.len() { rhs[i] } else { 0 };
+        let (sub, underflow_lhs) = minuend.overflowing_sub(lhs[i]);
+        let (sub, underflow_borrow) = sub.overflowing_sub(borrow as u64);
+        borrow = underflow_lhs || underflow_borrow;
+        lhs[i] = sub;
+    });
+    assert!(!borrow, "Minuend is less than subtrahend");
+}
+
+/// Sets `slice` to its two's complement, i.e. to $B^n - \text{slice}$, where $n$ is the length of the slice, and $B = 2^{64}$.
+///
+/// Zero stays zero.
+pub(crate) const fn neg_assign(slice: &mut [u64]) {
+    const_for!(i in 0..slice.len() => slice[i] = !slice[i]);
+    add_assign(slice, &[1]);
+}
+
+/// Adds signed `rhs` to signed `lhs`. Numbers are represented by their magnitude and a sign flag (`true` for negative).
+///
+/// ### Returns
+/// Sign of the result. Zero is never negative.
+pub(crate) const fn signed_add_assign(
+    lhs: &mut [u64],
+    lhs_negative: bool,
+    rhs: &[u64],
+    rhs_negative: bool,
+) -> bool {
+    if lhs_negative == rhs_negative {
+        let carry = add_assign(lhs, rhs);
+        assert!(!carry, "Signed sum does not fit");
+        return lhs_negative && significant_len(lhs) > 0;
+    }
+    if cmp(lhs, rhs).is_ge() {
+        sub_assign(lhs, rhs);
+        lhs_negative && significant_len(lhs) > 0
+    } else {
+        rsub_assign(lhs, rhs);
+        rhs_negative
+    }
+}
+
+/// Shifts limbs by `shift < 64` bits to the left.
+///
+/// ### Returns
+/// Bits shifted out of the upper-most limb.
+pub(crate) const fn shl_assign(slice: &mut [u64], shift: u32) -> u64 {
+    debug_assert!(shift < u64::BITS);
+    if shift == 0 {
+        return 0;
+    }
+    let mut carry = 0u64;
+    const_for!(i in 0..slice.len() => {
+        let limb = slice[i];
+        slice[i] = (limb << shift) | carry;
+        carry = limb >> (u64::BITS - shift);
+    });
+    carry
+}
+
+/// Shifts limbs by `shift < 64` bits to the right.
+///
+/// ### Returns
+/// Bits shifted out of the lower-most limb, as upper bits of the returned value.
+pub(crate) const fn shr_assign(slice: &mut [u64], shift: u32) -> u64 {
+    debug_assert!(shift < u64::BITS);
+    if shift == 0 {
+        return 0;
+    }
+    let mut carry = 0u64;
+    const_for!(i in (0..slice.len()).rev() => {
+        let limb = slice[i];
+        slice[i] = (limb >> shift) | carry;
+        carry = limb << (u64::BITS - shift);
+    });
+    carry
+}
+
+/// Single-limb divisor, prepared for division without hardware 128-bit division (Möller & Granlund, "Improved division by invariant integers").
+pub(crate) struct LimbDivisor {
+    /// Divisor, shifted to have the upper-most bit set
+    normalized: u64,
+    shift: u32,
+    /// $\lfloor (2^{128} - 1) / d \rfloor - 2^{64}$, where $d$ is the normalized divisor
+    reciprocal: u64,
+}
+
+impl LimbDivisor {
+    /// ### Panics
+    /// If `divisor` is zero.
+    pub(crate) const fn new(divisor: u64) -> Self {
+        assert!(divisor != 0, "Division by zero");
+        let shift = divisor.leading_zeros();
+        let normalized = divisor << shift;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "normalized divisor has upper bit set, so the quotient is less than 2^65, and 2^64 is subtracted"
+        )]
+        let reciprocal = (u128::MAX / normalized as u128) as u64;
+        Self {
+            normalized,
+            shift,
+            reciprocal,
+        }
+    }
+
+    /// Divides `high * 2^64 + low` by the normalized divisor. `high` should be less than it.
+    #[inline]
+    const fn div_rem_normalized(&self, high: u64, low: u64) -> (u64, u64) {
+        // computed modulo 2^128, as in the paper
+        let estimate = (self.reciprocal as u128 * high as u128)
+            .wrapping_add((high as u128) << LIMB_BITS | low as u128);
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "splitting into limbs on purpose"
+        )]
+        let (mut quotient, estimate_low) = (
+            ((estimate >> LIMB_BITS) as u64).wrapping_add(1),
+            estimate as u64,
+        );
+        let mut remainder = low.wrapping_sub(quotient.wrapping_mul(self.normalized));
+        if remainder > estimate_low {
+            quotient = quotient.wrapping_sub(1);
+            remainder = remainder.wrapping_add(self.normalized);
+        }
+        if remainder >= self.normalized {
+            quotient += 1;
+            remainder -= self.normalized;
+        }
+        (quotient, remainder)
+    }
+
+    /// Limb of the `slice`, as if the whole slice was shifted by [`Self::shift`] bits to the left.
+    #[inline]
+    const fn shifted_limb(&self, slice: &[u64], i: usize) -> u64 {
+        if self.shift == 0 {
+            return slice[i];
+        }
+        let lower = if i > 0 {
+            slice[i - 1] >> (u64::BITS - self.shift)
+        } else {
+            0
+        };
+        (slice[i] << self.shift) | lower
+    }
+
+    /// Bits of the upper-most limb shifted out by the normalization, which form the initial remainder.
+    #[inline]
+    const fn spill(&self, slice: &[u64]) -> u64 {
+        if self.shift == 0 || slice.is_empty() {
+            return 0;
+        }
+        slice[slice.len() - 1] >> (u64::BITS - self.shift)
+    }
+
+    /// Divides limbs by the divisor, in place.
+    ///
+    /// ### Returns
+    /// The remainder.
+    pub(crate) const fn div_rem_assign(&self, slice: &mut [u64]) -> u64 {
+        let mut remainder = self.spill(slice);
+        const_for!(i in (0..slice.len()).rev() => {
+            // lower limb is read before it's overwritten
+            let (quotient, new_remainder) = self.div_rem_normalized(remainder, self.shifted_limb(slice, i));
+            slice[i] = quotient;
+            remainder = new_remainder;
+        });
+        remainder >> self.shift
+    }
+
+    /// Finds the remainder of division of limbs by the divisor.
+    pub(crate) const fn rem(&self, slice: &[u64]) -> u64 {
+        let mut remainder = self.spill(slice);
+        const_for!(i in (0..slice.len()).rev() => {
+            remainder = self.div_rem_normalized(remainder, self.shifted_limb(slice, i)).1;
+        });
+        remainder >> self.shift
+    }
+}
+
+/// Divides limbs by a single-limb `divisor`, in place.
+///
+/// ### Returns
+/// The remainder.
+pub(crate) const fn div_rem_limb_assign(slice: &mut [u64], divisor: u64) -> u64 {
+    LimbDivisor::new(divisor).div_rem_assign(slice)
+}
+
+/// Multiplies limbs by a single-limb `factor`, in place.
+///
+/// ### Returns
+/// Limb carried out of the upper-most one.
+pub(crate) const fn mul_limb_assign(slice: &mut [u64], factor: u64) -> u64 {
+    let mut carry = 0u64;
+    const_for!(i in 0..slice.len() => {
+        let product = slice[i] as u128 * factor as u128 + carry as u128;
+        #[allow(clippy::cast_possible_truncation, reason = "splitting into limbs on purpose")]
+        {
+            slice[i] = product as u64;
+            carry = (product >> LIMB_BITS) as u64;
+        }
+    });
+    carry
+}
@@ -0,0 +1,501 @@
+//! Multiplication algorithms over limb slices.
+
+use const_for::const_for;
+
+use super::{
+    limbs::{self, range, range_mut},
+    ntt,
+};
+use crate::{LIMB_BITS, LIMBS};
+
+/// Operands shorter than this many limbs are multiplied with the schoolbook algorithm.
+pub(crate) const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Operands at least this many limbs long are multiplied with Toom-3, if they are balanced enough.
+pub(crate) const TOOM3_THRESHOLD: usize = 128;
+
+/// Operands shorter than this many limbs are squared with the schoolbook algorithm.
+///
+/// Schoolbook squaring only computes half of the partial products, so it stays faster for longer.
+pub(crate) const SQR_KARATSUBA_THRESHOLD: usize = 48;
+
+/// Operands at least this many limbs long are squared with Toom-3.
+pub(crate) const SQR_TOOM3_THRESHOLD: usize = 128;
+
+/// Number of scratch limbs [`mul`] (or [`sqr`]) needs for operands up to `len` limbs long.
+pub(crate) const fn scratch_len(len: usize) -> usize {
+    if len < KARATSUBA_THRESHOLD {
+        return 0;
+    }
+    // see `karatsuba` and `toom3` for the buffers they allocate
+    let half = len.div_ceil(2) + 1;
+    let karatsuba = 4 * half + scratch_len(half);
+    let third = len.div_ceil(3) + 1;
+    let toom3 = 8 * third + scratch_len(third);
+    if karatsuba > toom3 { karatsuba } else { toom3 }
+}
+
+const SCRATCH_LEN: usize = scratch_len(LIMBS);
+
+/// Number of scratch limbs [`mul_truncated`] needs for operands with specified lengths.
+///
+/// Schoolbook multiplication needs none, transform multiplication needs [`ntt::scratch_len`], and the rest need a buffer for the full product along with [`scratch_len`].
+pub(crate) const fn truncated_scratch_len(lhs_len: usize, rhs_len: usize) -> usize {
+    if lhs_len < KARATSUBA_THRESHOLD || rhs_len < KARATSUBA_THRESHOLD {
+        0
+    } else if ntt::worthwhile(lhs_len, rhs_len) {
+        ntt::scratch_len(lhs_len, rhs_len)
+    } else {
+        TRUNCATED_SCRATCH_LEN
+    }
+}
+
+/// Number of scratch limbs [`sqr_truncated`] needs for an operand of specified length, see [`truncated_scratch_len`].
+pub(crate) const fn sqr_truncated_scratch_len(len: usize) -> usize {
+    if len < SQR_KARATSUBA_THRESHOLD {
+        0
+    } else if ntt::worthwhile(len, len) {
+        ntt::scratch_len(len, len)
+    } else {
+        TRUNCATED_SCRATCH_LEN
+    }
+}
+
+/// Scratch limbs enough for [`mul_truncated`] (or [`sqr_truncated`]) to multiply any pair of [`u206265`](crate::u206265)s without a transform.
+pub(crate) const TRUNCATED_SCRATCH_LEN: usize = 2 * LIMBS + SCRATCH_LEN;
+
+/// Schoolbook multiplication. Partial products landing past the upper-most limb of `out` are simply dropped.
+pub(crate) const fn schoolbook(out: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+    limbs::zero(out);
+    const_for!(i in 0..lhs.len() => {
+        if i >= out.len() {
+            break;
+        }
+        if lhs[i] == 0 {
+            continue;
+        }
+        let lhs_limb = lhs[i] as u128;
+        let mut carry = 0u64;
+        const_for!(j in 0..rhs.len() => {
+            if i + j >= out.len() {
+                break;
+            }
+            let product = lhs_limb * rhs[j] as u128 + out[i + j] as u128 + carry as u128;
+            #[allow(clippy::cast_possible_truncation, reason = "taking the lower limb on purpose")]
+            {out[i + j] = product as u64;}
+            carry = (product >> LIMB_BITS) as u64;
+        });
+        if i + rhs.len() < out.len() {
+            out[i + rhs.len()] = carry;
+        }
+    });
+}
+
+/// Multiplies `lhs` by `rhs`, writing the lower [`LIMBS`] limbs of the product into `out`.
+///
+/// Algorithm is picked depending on the operand lengths. `scratch` should be at least [`truncated_scratch_len`] limbs long.
+pub(crate) const fn mul_truncated(
+    out: &mut [u64; LIMBS],
+    lhs: &[u64],
+    rhs: &[u64],
+    scratch: &mut [u64],
+) {
+    if lhs.len() < KARATSUBA_THRESHOLD || rhs.len() < KARATSUBA_THRESHOLD {
+        schoolbook(out, lhs, rhs);
+        return;
+    }
+    let product_len = lhs.len() + rhs.len();
+    if ntt::worthwhile(lhs.len(), rhs.len()) {
+        limbs::zero(out);
+        let out_len = if product_len < LIMBS {
+            product_len
+        } else {
+            LIMBS
+        };
+        ntt::mul(range_mut(out, 0, out_len), lhs, rhs, scratch);
+        return;
+    }
+    if product_len <= LIMBS {
+        limbs::zero(out);
+        mul(range_mut(out, 0, product_len), lhs, rhs, scratch);
+    } else {
+        let (product, scratch) = scratch.split_at_mut(product_len);
+        mul(product, lhs, rhs, scratch);
+        limbs::copy(out, range(product, 0, LIMBS));
+    }
+}
+
+/// Multiplies `lhs` by `rhs`, writing the full product into `out`.
+///
+/// `out` should be exactly `lhs.len() + rhs.len()` limbs long, and `scratch` should be at least [`scratch_len`] limbs long.
+pub(crate) const fn mul(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    debug_assert!(out.len() == lhs.len() + rhs.len());
+    let (lhs, rhs) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    if rhs.len() < KARATSUBA_THRESHOLD {
+        schoolbook(out, lhs, rhs);
+    } else if 2 * rhs.len() <= lhs.len() {
+        unbalanced(out, lhs, rhs, scratch);
+    } else if rhs.len() >= TOOM3_THRESHOLD && rhs.len() > 2 * lhs.len().div_ceil(3) {
+        toom3(out, lhs, rhs, scratch);
+    } else {
+        karatsuba(out, lhs, rhs, scratch);
+    }
+}
+
+/// Same as [`mul`], but operands are trimmed first and `out` is allowed to be longer than the product.
+pub(crate) const fn mul_trimmed(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    let lhs = limbs::trim(lhs);
+    let rhs = limbs::trim(rhs);
+    limbs::zero(out);
+    mul(range_mut(out, 0, lhs.len() + rhs.len()), lhs, rhs, scratch);
+}
+
+/// Multiplies `lhs` by much shorter `rhs`, by splitting `lhs` into `rhs`-sized chunks.
+const fn unbalanced(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    limbs::zero(out);
+    let (chunk_product, scratch) = scratch.split_at_mut(2 * rhs.len());
+    let mut start = 0;
+    while start < lhs.len() {
+        let end = if start + rhs.len() < lhs.len() {
+            start + rhs.len()
+        } else {
+            lhs.len()
+        };
+        let chunk = range(lhs, start, end);
+        let product = range_mut(chunk_product, 0, chunk.len() + rhs.len());
+        mul(product, chunk, rhs, scratch);
+        let carry = limbs::add_assign(range_mut(out, start, out.len()), product);
+        debug_assert!(!carry, "Product cannot exceed its limbs");
+        start = end;
+    }
+}
+
+/// Karatsuba multiplication: three half-sized multiplications instead of four.
+///
+/// `rhs` should be longer than half of `lhs`.
+const fn karatsuba(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    let half = lhs.len() / 2;
+    let (lhs_low, lhs_high) = lhs.split_at(half);
+    let (rhs_low, rhs_high) = rhs.split_at(half);
+
+    // low and high parts go right into their places
+    {
+        let (out_low, out_high) = out.split_at_mut(2 * half);
+        mul(out_low, lhs_low, rhs_low, scratch);
+        mul(out_high, lhs_high, rhs_high, scratch);
+    }
+
+    // middle part is (lhs_low + lhs_high) * (rhs_low + rhs_high) - low - high
+    let lhs_sum_len = lhs_high.len() + 1;
+    let rhs_sum_len = if rhs_high.len() > rhs_low.len() {
+        rhs_high.len()
+    } else {
+        rhs_low.len()
+    } + 1;
+    let (lhs_sum, scratch) = scratch.split_at_mut(lhs_sum_len);
+    let (rhs_sum, scratch) = scratch.split_at_mut(rhs_sum_len);
+    let (middle, scratch) = scratch.split_at_mut(lhs_sum_len + rhs_sum_len);
+    limbs::copy(lhs_sum, lhs_high);
+    limbs::add_assign(lhs_sum, lhs_low);
+    limbs::copy(rhs_sum, rhs_low);
+    limbs::add_assign(rhs_sum, rhs_high);
+    mul_trimmed(middle, lhs_sum, rhs_sum, scratch);
+    limbs::sub_assign(middle, range(out, 0, 2 * half));
+    limbs::sub_assign(middle, range(out, 2 * half, out.len()));
+
+    let carry = limbs::add_assign(range_mut(out, half, out.len()), middle);
+    debug_assert!(!carry, "Product cannot exceed its limbs");
+}
+
+/// Toom-3 multiplication: five third-sized multiplications instead of nine.
+///
+/// Operands are treated as polynomials of a limb power, evaluated at $0$, $1$, $-1$, $-2$ and $\infty$.
+/// Interpolation follows Bodrato's sequence. `rhs` should be longer than two thirds of `lhs`.
+const fn toom3(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    let third = lhs.len().div_ceil(3);
+    let (lhs_0, lhs_rest) = lhs.split_at(third);
+    let (lhs_1, lhs_2) = lhs_rest.split_at(third);
+    let (rhs_0, rhs_rest) = rhs.split_at(third);
+    let (rhs_1, rhs_2) = rhs_rest.split_at(third);
+
+    // values at 0 and infinity go right into their places
+    {
+        let (out_0, out_rest) = out.split_at_mut(2 * third);
+        let (out_middle, out_inf) = out_rest.split_at_mut(2 * third);
+        mul(out_0, lhs_0, rhs_0, scratch);
+        limbs::zero(out_middle);
+        mul(out_inf, lhs_2, rhs_2, scratch);
+    }
+
+    let value_len = 2 * third + 2;
+    let (value_1, scratch) = scratch.split_at_mut(value_len);
+    let (value_m1, scratch) = scratch.split_at_mut(value_len);
+    let (value_m2, scratch) = scratch.split_at_mut(value_len);
+    let (lhs_eval, scratch) = scratch.split_at_mut(third + 1);
+    let (rhs_eval, scratch) = scratch.split_at_mut(third + 1);
+
+    // at -1
+    let lhs_negative = toom3_eval_m1(lhs_eval, lhs_0, lhs_1, lhs_2);
+    let rhs_negative = toom3_eval_m1(rhs_eval, rhs_0, rhs_1, rhs_2);
+    mul_trimmed(value_m1, lhs_eval, rhs_eval, scratch);
+    let negative_m1 = lhs_negative != rhs_negative;
+
+    // at 1, which is the value at -1 plus twice the middle coefficient
+    let lhs_negative = limbs::signed_add_assign(lhs_eval, lhs_negative, lhs_1, false);
+    let lhs_negative = limbs::signed_add_assign(lhs_eval, lhs_negative, lhs_1, false);
+    let rhs_negative = limbs::signed_add_assign(rhs_eval, rhs_negative, rhs_1, false);
+    let rhs_negative = limbs::signed_add_assign(rhs_eval, rhs_negative, rhs_1, false);
+    debug_assert!(!lhs_negative && !rhs_negative);
+    mul_trimmed(value_1, lhs_eval, rhs_eval, scratch);
+
+    // at -2
+    let lhs_negative = toom3_eval_m2(lhs_eval, lhs_0, lhs_1, lhs_2);
+    let rhs_negative = toom3_eval_m2(rhs_eval, rhs_0, rhs_1, rhs_2);
+    mul_trimmed(value_m2, lhs_eval, rhs_eval, scratch);
+    let negative_m2 = lhs_negative != rhs_negative;
+
+    toom3_interpolate(
+        out,
+        third,
+        [value_1, value_m1, value_m2],
+        [negative_m1, negative_m2],
+    );
+}
+
+/// Turns values of the product at $1$, $-1$ and $-2$ into its middle coefficients, and adds them to `out`.
+///
+/// `out` should already contain coefficients at $0$ and $\infty$ in their places. Values at $-1$ and $-2$ are signed, with their signs in `negative`.
+const fn toom3_interpolate(
+    out: &mut [u64],
+    third: usize,
+    values: [&mut [u64]; 3],
+    negative: [bool; 2],
+) {
+    let [value_1, value_m1, value_m2] = values;
+    let [mut negative_m1, mut negative_m2] = negative;
+    let value_0 = range(out, 0, 2 * third);
+    let value_inf = range(out, 4 * third, out.len());
+    // r3 = (r(-2) - r(1)) / 3
+    negative_m2 = limbs::signed_add_assign(value_m2, negative_m2, value_1, true);
+    let remainder = limbs::div_rem_limb_assign(value_m2, 3);
+    debug_assert!(remainder == 0, "Division should be exact");
+    // r1 = (r(1) - r(-1)) / 2
+    let mut negative_1 = limbs::signed_add_assign(value_1, false, value_m1, !negative_m1);
+    let remainder = limbs::shr_assign(value_1, 1);
+    debug_assert!(remainder == 0, "Division should be exact");
+    // r2 = r(-1) - r(0)
+    negative_m1 = limbs::signed_add_assign(value_m1, negative_m1, value_0, true);
+    // r3 = (r2 - r3) / 2 + 2 r(inf)
+    negative_m2 = limbs::signed_add_assign(value_m2, !negative_m2, value_m1, negative_m1);
+    let remainder = limbs::shr_assign(value_m2, 1);
+    debug_assert!(remainder == 0, "Division should be exact");
+    negative_m2 = limbs::signed_add_assign(value_m2, negative_m2, value_inf, false);
+    negative_m2 = limbs::signed_add_assign(value_m2, negative_m2, value_inf, false);
+    // r2 = r2 + r1 - r(inf)
+    negative_m1 = limbs::signed_add_assign(value_m1, negative_m1, value_1, negative_1);
+    negative_m1 = limbs::signed_add_assign(value_m1, negative_m1, value_inf, true);
+    // r1 = r1 - r3
+    negative_1 = limbs::signed_add_assign(value_1, negative_1, value_m2, !negative_m2);
+    debug_assert!(
+        !negative_1 && !negative_m1 && !negative_m2,
+        "Product coefficients cannot be negative"
+    );
+
+    let carry = limbs::add_assign(range_mut(out, third, out.len()), value_1);
+    debug_assert!(!carry, "Product cannot exceed its limbs");
+    let carry = limbs::add_assign(range_mut(out, 2 * third, out.len()), value_m1);
+    debug_assert!(!carry, "Product cannot exceed its limbs");
+    let carry = limbs::add_assign(range_mut(out, 3 * third, out.len()), value_m2);
+    debug_assert!(!carry, "Product cannot exceed its limbs");
+}
+
+/// Evaluates $c_0 - c_1 + c_2$ into `out`, returning its sign.
+const fn toom3_eval_m1(out: &mut [u64], c_0: &[u64], c_1: &[u64], c_2: &[u64]) -> bool {
+    limbs::copy(out, c_0);
+    limbs::add_assign(out, c_2);
+    limbs::signed_add_assign(out, false, c_1, true)
+}
+
+/// Evaluates $c_0 - 2 c_1 + 4 c_2$ into `out`, returning its sign.
+const fn toom3_eval_m2(out: &mut [u64], c_0: &[u64], c_1: &[u64], c_2: &[u64]) -> bool {
+    limbs::copy(out, c_2);
+    limbs::shl_assign(out, 2);
+    limbs::add_assign(out, c_0);
+    let negative = limbs::signed_add_assign(out, false, c_1, true);
+    limbs::signed_add_assign(out, negative, c_1, true)
+}
+
+/// Schoolbook squaring. Partial products landing past the upper-most limb of `out` are simply dropped.
+///
+/// Products of different limbs come in pairs, so these are only computed once, and then doubled.
+pub(crate) const fn schoolbook_sqr(out: &mut [u64], value: &[u64]) {
+    limbs::zero(out);
+    // products of different limbs
+    const_for!(i in 0..value.len() => {
+        if 2 * i + 1 >= out.len() {
+            break;
+        }
+        if value[i] == 0 {
+            continue;
+        }
+        let value_limb = value[i] as u128;
+        let mut carry = 0u64;
+        const_for!(j in (i + 1)..value.len() => {
+            if i + j >= out.len() {
+                break;
+            }
+            let product = value_limb * value[j] as u128 + out[i + j] as u128 + carry as u128;
+            #[allow(clippy::cast_possible_truncation, reason = "taking the lower limb on purpose")]
+            {out[i + j] = product as u64;}
+            carry = (product >> LIMB_BITS) as u64;
+        });
+        if i + value.len() < out.len() {
+            out[i + value.len()] = carry;
+        }
+    });
+    limbs::shl_assign(out, 1);
+
+    // squares of the limbs
+    let mut carry = 0u64;
+    const_for!(i in 0..value.len() => {
+        if 2 * i >= out.len() {
+            break;
+        }
+        let square = value[i] as u128 * value[i] as u128;
+        #[allow(clippy::cast_possible_truncation, reason = "splitting into limbs on purpose")]
+        let (low, high) = (square as u64, (square >> LIMB_BITS) as u64);
+        let (sum, overflow_square) = out[2 * i].overflowing_add(low);
+        let (sum, overflow_carry) = sum.overflowing_add(carry);
+        out[2 * i] = sum;
+        carry = (overflow_square || overflow_carry) as u64;
+        if 2 * i + 1 >= out.len() {
+            break;
+        }
+        let (sum, overflow_square) = out[2 * i + 1].overflowing_add(high);
+        let (sum, overflow_carry) = sum.overflowing_add(carry);
+        out[2 * i + 1] = sum;
+        carry = (overflow_square || overflow_carry) as u64;
+    });
+    let tail = 2 * value.len();
+    if tail < out.len() {
+        limbs::add_assign(range_mut(out, tail, out.len()), &[carry]);
+    }
+}
+
+/// Squares `value`, writing the lower [`LIMBS`] limbs of the result into `out`.
+///
+/// Same as [`mul_truncated`] with both operands being `value`. `scratch` should be at least [`sqr_truncated_scratch_len`] limbs long.
+pub(crate) const fn sqr_truncated(out: &mut [u64; LIMBS], value: &[u64], scratch: &mut [u64]) {
+    if value.len() < SQR_KARATSUBA_THRESHOLD {
+        schoolbook_sqr(out, value);
+        return;
+    }
+    let product_len = 2 * value.len();
+    if ntt::worthwhile(value.len(), value.len()) {
+        limbs::zero(out);
+        let out_len = if product_len < LIMBS {
+            product_len
+        } else {
+            LIMBS
+        };
+        ntt::sqr(range_mut(out, 0, out_len), value, scratch);
+        return;
+    }
+    if product_len <= LIMBS {
+        limbs::zero(out);
+        sqr(range_mut(out, 0, product_len), value, scratch);
+    } else {
+        let (product, scratch) = scratch.split_at_mut(product_len);
+        sqr(product, value, scratch);
+        limbs::copy(out, range(product, 0, LIMBS));
+    }
+}
+
+/// Squares `value`, writing the full result into `out`.
+///
+/// `out` should be exactly `2 * value.len()` limbs long, and `scratch` should be at least [`scratch_len`] limbs long.
+pub(crate) const fn sqr(out: &mut [u64], value: &[u64], scratch: &mut [u64]) {
+    debug_assert!(out.len() == 2 * value.len());
+    if value.len() < SQR_KARATSUBA_THRESHOLD {
+        schoolbook_sqr(out, value);
+    } else if value.len() >= SQR_TOOM3_THRESHOLD {
+        toom3_sqr(out, value, scratch);
+    } else {
+        karatsuba_sqr(out, value, scratch);
+    }
+}
+
+/// Same as [`sqr`], but `value` is trimmed first and `out` is allowed to be longer than the result.
+const fn sqr_trimmed(out: &mut [u64], value: &[u64], scratch: &mut [u64]) {
+    let value = limbs::trim(value);
+    limbs::zero(out);
+    sqr(range_mut(out, 0, 2 * value.len()), value, scratch);
+}
+
+/// Karatsuba squaring: three half-sized squarings instead of four multiplications, see [`karatsuba`].
+const fn karatsuba_sqr(out: &mut [u64], value: &[u64], scratch: &mut [u64]) {
+    let half = value.len() / 2;
+    let (low, high) = value.split_at(half);
+
+    {
+        let (out_low, out_high) = out.split_at_mut(2 * half);
+        sqr(out_low, low, scratch);
+        sqr(out_high, high, scratch);
+    }
+
+    // middle part is (low + high)^2 - low^2 - high^2
+    let sum_len = high.len() + 1;
+    let (sum, scratch) = scratch.split_at_mut(sum_len);
+    let (middle, scratch) = scratch.split_at_mut(2 * sum_len);
+    limbs::copy(sum, high);
+    limbs::add_assign(sum, low);
+    sqr_trimmed(middle, sum, scratch);
+    limbs::sub_assign(middle, range(out, 0, 2 * half));
+    limbs::sub_assign(middle, range(out, 2 * half, out.len()));
+
+    let carry = limbs::add_assign(range_mut(out, half, out.len()), middle);
+    debug_assert!(!carry, "Square cannot exceed its limbs");
+}
+
+/// Toom-3 squaring: five third-sized squarings, see [`toom3`].
+///
+/// Squares are never negative, which saves some sign juggling.
+const fn toom3_sqr(out: &mut [u64], value: &[u64], scratch: &mut [u64]) {
+    let third = value.len().div_ceil(3);
+    let (c_0, rest) = value.split_at(third);
+    let (c_1, c_2) = rest.split_at(third);
+
+    {
+        let (out_0, out_rest) = out.split_at_mut(2 * third);
+        let (out_middle, out_inf) = out_rest.split_at_mut(2 * third);
+        sqr(out_0, c_0, scratch);
+        limbs::zero(out_middle);
+        sqr(out_inf, c_2, scratch);
+    }
+
+    let value_len = 2 * third + 2;
+    let (value_1, scratch) = scratch.split_at_mut(value_len);
+    let (value_m1, scratch) = scratch.split_at_mut(value_len);
+    let (value_m2, scratch) = scratch.split_at_mut(value_len);
+    let (eval, scratch) = scratch.split_at_mut(third + 1);
+
+    // at -1
+    let negative = toom3_eval_m1(eval, c_0, c_1, c_2);
+    sqr_trimmed(value_m1, eval, scratch);
+
+    // at 1, which is the value at -1 plus twice the middle coefficient
+    let negative = limbs::signed_add_assign(eval, negative, c_1, false);
+    let negative = limbs::signed_add_assign(eval, negative, c_1, false);
+    debug_assert!(!negative);
+    sqr_trimmed(value_1, eval, scratch);
+
+    // at -2
+    toom3_eval_m2(eval, c_0, c_1, c_2);
+    sqr_trimmed(value_m2, eval, scratch);
+
+    toom3_interpolate(out, third, [value_1, value_m1, value_m2], [false, false]);
+}
@@ -0,0 +1,401 @@
+//! Multiplication via number-theoretic transform.
+//!
+//! Convolution of the limbs is computed modulo three word-sized primes, and then recombined with the Chinese remainder theorem.
+//! Coefficients of a convolution of two [`LIMBS`]-long operands are less than $2^{140}$,
+//! while product of the primes is about $2^{183}$, so the recombination is exact.
+
+use const_for::const_for;
+
+use super::limbs::{range, range_mut};
+use crate::{LIMB_BITS, LIMBS};
+
+/// Both operands should be at least this many limbs long for transform to be faster than [`super::mul::mul`].
+const NTT_THRESHOLD: usize = 1800;
+
+/// Decides, if transform multiplication of operands with specified lengths is expected to be faster than [`super::mul::mul`].
+///
+/// Transform length is always a power of two, so a product barely past one pays for almost twice the work. Transform is only used if it's at least $3/4$ full.
+pub(crate) const fn worthwhile(lhs_len: usize, rhs_len: usize) -> bool {
+    let product_len = lhs_len + rhs_len;
+    lhs_len >= NTT_THRESHOLD
+        && rhs_len >= NTT_THRESHOLD
+        && 4 * product_len >= 3 * (product_len - 1).next_power_of_two()
+}
+
+/// Transform length of the largest product.
+pub(crate) const MAX_LEN: usize = (2 * LIMBS).next_power_of_two();
+
+/// An NTT-friendly prime, along with constants for Montgomery arithmetic modulo it.
+///
+/// Montgomery radix is $2^{64}$. Twiddle factors are kept in Montgomery form, so that multiplying a plain number by them yields a plain number.
+struct Prime {
+    modulus: u64,
+    /// $-p^{-1} \bmod 2^{64}$
+    inv_neg: u64,
+    /// $2^{128} \bmod p$
+    r2: u64,
+    /// A primitive root modulo $p$
+    generator: u64,
+}
+
+impl Prime {
+    const fn new(modulus: u64, generator: u64) -> Self {
+        assert!(
+            modulus < 1 << 62,
+            "Montgomery reduction and sign masks require spare bits"
+        );
+        assert!(
+            (modulus - 1).trailing_zeros() as usize >= MAX_LEN.trailing_zeros() as usize,
+            "Prime should have roots of unity of the transform length"
+        );
+        // Newton iteration, each step doubles the number of correct bits
+        let mut inv = modulus;
+        const_for!(_ in 0..5 => inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv))));
+        let r = (1u128 << LIMB_BITS) % modulus as u128;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "value is reduced modulo u64"
+        )]
+        let r2 = (r * r % modulus as u128) as u64;
+        Self {
+            modulus,
+            inv_neg: inv.wrapping_neg(),
+            r2,
+            generator,
+        }
+    }
+
+    /// Montgomery multiplication, finds $a b 2^{-64} \bmod p$.
+    #[inline]
+    const fn mul(&self, lhs: u64, rhs: u64) -> u64 {
+        self.reduce_once(self.mul_lazy(lhs, rhs))
+    }
+
+    /// Same as [`Prime::mul`], but the result is only reduced to $[0, 2p)$.
+    ///
+    /// Product of the operands should be less than $2^{64} p$.
+    #[inline]
+    const fn mul_lazy(&self, lhs: u64, rhs: u64) -> u64 {
+        let product = lhs as u128 * rhs as u128;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "taking the lower limb on purpose"
+        )]
+        let m = (product as u64).wrapping_mul(self.inv_neg);
+        ((product + m as u128 * self.modulus as u128) >> LIMB_BITS) as u64
+    }
+
+    /// Reduces a number less than $2p$.
+    #[inline]
+    const fn reduce_once(&self, value: u64) -> u64 {
+        sub_wrapping(value, self.modulus, self.modulus)
+    }
+
+    /// Reduces a number less than $4p$ to $[0, 2p)$.
+    #[inline]
+    const fn reduce_twice(&self, value: u64) -> u64 {
+        sub_wrapping(value, 2 * self.modulus, 2 * self.modulus)
+    }
+
+    /// Finds `lhs - rhs` modulo $p$.
+    #[inline]
+    const fn sub(&self, lhs: u64, rhs: u64) -> u64 {
+        sub_wrapping(lhs, rhs, self.modulus)
+    }
+
+    /// Converts a plain number less than $p$ into Montgomery form.
+    #[inline]
+    const fn to_montgomery(&self, value: u64) -> u64 {
+        self.mul(value, self.r2)
+    }
+
+    /// Raises Montgomery-form `base` to the power of `exp`.
+    const fn pow(&self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = self.to_montgomery(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Inverse of plain `value`, in Montgomery form.
+    const fn inverse(&self, value: u64) -> u64 {
+        self.pow(self.to_montgomery(value % self.modulus), self.modulus - 2)
+    }
+
+    /// Fills `twiddles` with powers of a root of unity of order `2 * twiddles.len()`, in Montgomery form.
+    const fn twiddles(&self, twiddles: &mut [u64]) {
+        let order = 2 * twiddles.len() as u64;
+        let root = self.pow(
+            self.to_montgomery(self.generator),
+            (self.modulus - 1) / order,
+        );
+        let mut power = self.to_montgomery(1);
+        const_for!(i in 0..twiddles.len() => {
+            twiddles[i] = power;
+            power = self.mul(power, root);
+        });
+    }
+
+    /// Reduces `limbs` modulo $p$ into `values` (to $[0, 2p)$, actually), padding them with zeros.
+    const fn load(&self, values: &mut [u64], limbs: &[u64]) {
+        // multiplying by Montgomery form of 1 is much cheaper than the `%`
+        let one = self.to_montgomery(1);
+        const_for!(i in 0..values.len() => {
+            values[i] = if i < limbs.len() { self.mul_lazy(limbs[i], one) } else { 0 };
+        });
+    }
+
+    /// Forward transform (decimation in frequency). Output is left in bit-reversed order.
+    ///
+    /// Reduction is lazy (see Harvey, "Faster arithmetic for number-theoretic transforms"):
+    /// values are expected in $[0, 2p)$, and are left in $[0, 2p)$.
+    const fn forward(&self, values: &mut [u64], twiddles: &[u64]) {
+        let len = values.len();
+        let mut half = len / 2;
+        let mut stride = 1;
+        while half > 0 {
+            let mut start = 0;
+            while start < len {
+                const_for!(j in 0..half => {
+                    let u = values[start + j];
+                    let v = values[start + j + half];
+                    values[start + j] = self.reduce_twice(u + v);
+                    values[start + j + half] = self.mul_lazy(u + 2 * self.modulus - v, twiddles[j * stride]);
+                });
+                start += 2 * half;
+            }
+            half >>= 1;
+            stride <<= 1;
+        }
+    }
+
+    /// Inverse transform (decimation in time), without the final division by length. Input is expected in bit-reversed order.
+    ///
+    /// Reduction is lazy: values are expected in $[0, 4p)$, and are left in $[0, 4p)$.
+    const fn inverse_unscaled(&self, values: &mut [u64], twiddles: &[u64]) {
+        let len = values.len();
+        let mut half = 1;
+        let mut stride = len / 2;
+        while half < len {
+            let mut start = 0;
+            while start < len {
+                const_for!(j in 0..half => {
+                    let u = self.reduce_twice(values[start + j]);
+                    let v = values[start + j + half];
+                    if j == 0 {
+                        let v = self.mul_lazy(v, twiddles[0]);
+                        values[start + j] = u + v;
+                        values[start + j + half] = u + 2 * self.modulus - v;
+                    } else {
+                        // w^{-j} = w^{len - j} = -w^{len / 2 - j}
+                        let v = self.mul_lazy(v, twiddles[len / 2 - j * stride]);
+                        values[start + j] = u + 2 * self.modulus - v;
+                        values[start + j + half] = u + v;
+                    }
+                });
+                start += 2 * half;
+            }
+            half <<= 1;
+            stride >>= 1;
+        }
+    }
+
+    /// Finds cyclic convolution of `lhs` and `rhs` modulo $p$, leaving it in `lhs`.
+    ///
+    /// Values are scaled by $2^{-64} \cdot \text{length}$ and only reduced to $[0, 4p)$, see [`Prime::unscale`].
+    const fn convolve(&self, lhs: &mut [u64], rhs: &mut [u64], twiddles: &mut [u64]) {
+        self.twiddles(twiddles);
+        self.forward(lhs, twiddles);
+        self.forward(rhs, twiddles);
+        const_for!(i in 0..lhs.len() => lhs[i] = self.mul_lazy(lhs[i], rhs[i]));
+        self.inverse_unscaled(lhs, twiddles);
+    }
+
+    /// Same as [`Prime::convolve`], but convolves `values` with themselves.
+    const fn convolve_square(&self, values: &mut [u64], twiddles: &mut [u64]) {
+        self.twiddles(twiddles);
+        self.forward(values, twiddles);
+        const_for!(i in 0..values.len() => values[i] = self.mul_lazy(values[i], values[i]));
+        self.inverse_unscaled(values, twiddles);
+    }
+
+    /// Constant turning output of [`Prime::convolve`] into plain residues, when multiplied by.
+    const fn unscale(&self, len: usize) -> u64 {
+        self.to_montgomery(self.to_montgomery(self.mul(self.inverse(len as u64), 1)))
+    }
+}
+
+/// Finds `lhs - rhs`, adding `modulus` back if it went negative.
+///
+/// Transforms are full of unpredictable comparisons, so this is done with a sign mask instead of a branch.
+/// Moduli are less than $2^{62}$, so the difference always fits into 63 bits, leaving the sign bit alone.
+#[inline]
+const fn sub_wrapping(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    let difference = lhs.wrapping_sub(rhs);
+    #[allow(
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss,
+        reason = "extracting the sign mask"
+    )]
+    let mask = ((difference as i64) >> 63) as u64;
+    difference.wrapping_add(modulus & mask)
+}
+
+const PRIMES: [Prime; 3] = [
+    Prime::new(4_179_340_454_199_820_289, 3), // 29 * 2^57 + 1
+    Prime::new(2_485_986_994_308_513_793, 5), // 69 * 2^55 + 1
+    Prime::new(1_945_555_039_024_054_273, 5), // 27 * 2^56 + 1
+];
+const _: () = assert!(
+    PRIMES[0].modulus < 2 * PRIMES[1].modulus
+        && PRIMES[0].modulus < 4 * PRIMES[2].modulus
+        && PRIMES[1].modulus < 2 * PRIMES[2].modulus,
+    "Recombination reduces residues with subtractions"
+);
+
+/// Garner's constants, in Montgomery form
+const INV_P0_MOD_P1: u64 = PRIMES[1].inverse(PRIMES[0].modulus);
+const INV_P0_MOD_P2: u64 = PRIMES[2].inverse(PRIMES[0].modulus);
+const INV_P1_MOD_P2: u64 = PRIMES[2].inverse(PRIMES[1].modulus);
+
+/// Number of primes the convolution is computed modulo.
+pub(crate) const PRIMES_COUNT: usize = PRIMES.len();
+
+/// Transform length for a product of operands with specified lengths.
+pub(crate) const fn transform_len(lhs_len: usize, rhs_len: usize) -> usize {
+    // convolution has one coefficient less than the product has limbs
+    let len = (lhs_len + rhs_len - 1).next_power_of_two();
+    // transform of length 1 has no twiddles to speak of
+    let len = if len < 2 { 2 } else { len };
+    assert!(len <= MAX_LEN, "Operands are too long");
+    len
+}
+
+/// Number of scratch limbs [`residues`] needs for transform of length `len`: transform of `rhs` and the twiddles.
+pub(crate) const fn residues_scratch_len(len: usize) -> usize {
+    len + len / 2
+}
+
+/// Number of scratch limbs [`mul`] (or [`sqr`]) needs for operands with specified lengths: residues modulo each of the primes, and scratch for [`residues`].
+pub(crate) const fn scratch_len(lhs_len: usize, rhs_len: usize) -> usize {
+    let len = transform_len(lhs_len, rhs_len);
+    PRIMES_COUNT * len + residues_scratch_len(len)
+}
+
+/// Scratch limbs enough for any pair of [`u206265`](crate::u206265)s.
+pub(crate) const SCRATCH_LEN: usize = scratch_len(LIMBS, LIMBS);
+
+/// Finds convolution of `lhs` and `rhs` modulo `p`-th prime, writing its scaled residues into `out`.
+///
+/// `out` should be exactly [`transform_len`] long, and `scratch` should be at least [`residues_scratch_len`] long.
+/// Convolutions modulo different primes are independent, and are only combined by [`recombine`].
+pub(crate) const fn residues(
+    out: &mut [u64],
+    p: usize,
+    lhs: &[u64],
+    rhs: &[u64],
+    scratch: &mut [u64],
+) {
+    let len = out.len();
+    let prime = &PRIMES[p];
+    let (rhs_values, twiddles) = scratch.split_at_mut(len);
+    prime.load(out, lhs);
+    prime.load(rhs_values, rhs);
+    prime.convolve(out, rhs_values, range_mut(twiddles, 0, len / 2));
+}
+
+/// Multiplies `lhs` by `rhs`, writing lower limbs of the product into `out`.
+///
+/// `out` should not be longer than the product, and `scratch` should be at least [`scratch_len`] limbs long.
+pub(crate) const fn mul(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    assert!(
+        !lhs.is_empty() && !rhs.is_empty(),
+        "Operands should not be empty"
+    );
+    debug_assert!(out.len() <= lhs.len() + rhs.len());
+    let len = transform_len(lhs.len(), rhs.len());
+
+    let (values, scratch) = scratch.split_at_mut(PRIMES_COUNT * len);
+    const_for!(p in 0..PRIMES_COUNT => {
+        residues(range_mut(values, p * len, (p + 1) * len), p, lhs, rhs, scratch);
+    });
+
+    recombine(
+        out,
+        [
+            range(values, 0, len),
+            range(values, len, 2 * len),
+            range(values, 2 * len, 3 * len),
+        ],
+    );
+}
+
+/// Squares `value`, writing lower limbs of the result into `out`.
+///
+/// Same as [`mul`] with both operands being `value`, but only needs one forward transform per prime.
+pub(crate) const fn sqr(out: &mut [u64], value: &[u64], scratch: &mut [u64]) {
+    assert!(!value.is_empty(), "Operand should not be empty");
+    debug_assert!(out.len() <= 2 * value.len());
+    let len = transform_len(value.len(), value.len());
+
+    let (residues, scratch) = scratch.split_at_mut(PRIMES_COUNT * len);
+    let twiddles = range_mut(scratch, 0, len / 2);
+    const_for!(p in 0..PRIMES_COUNT => {
+        let values = range_mut(residues, p * len, (p + 1) * len);
+        PRIMES[p].load(values, value);
+        PRIMES[p].convolve_square(values, twiddles);
+    });
+
+    recombine(
+        out,
+        [
+            range(residues, 0, len),
+            range(residues, len, 2 * len),
+            range(residues, 2 * len, 3 * len),
+        ],
+    );
+}
+
+/// Recombines scaled residues of the convolution into limbs, propagating the carries.
+///
+/// `out` may be longer than the convolution, in which case the remaining limbs receive the carry.
+pub(crate) const fn recombine(out: &mut [u64], residues: [&[u64]; PRIMES_COUNT]) {
+    let len = residues[0].len();
+    let [p0, p1, p2] = &PRIMES;
+    let unscale = [p0.unscale(len), p1.unscale(len), p2.unscale(len)];
+    let mut carry = 0u128;
+    const_for!(i in 0..out.len() => {
+        let (c0, c1, c2);
+        if i >= len {
+            (c0, c1, c2) = (0, 0, 0);
+        } else {
+            let r0 = p0.mul(residues[0][i], unscale[0]);
+            let r1 = p1.mul(residues[1][i], unscale[1]);
+            let r2 = p2.mul(residues[2][i], unscale[2]);
+            // coefficient is x0 + p0 (x1 + p1 x2)
+            let x0 = r0;
+            // primes are close enough to reduce with a couple of subtractions
+            let x1 = p1.mul(p1.sub(r1, p1.reduce_once(x0)), INV_P0_MOD_P1);
+            let x2 = p2.mul(p2.sub(r2, p2.reduce_once(p2.reduce_twice(x0))), INV_P0_MOD_P2);
+            let x2 = p2.mul(p2.sub(x2, p2.reduce_once(x1)), INV_P1_MOD_P2);
+            let tail = x1 as u128 + p1.modulus as u128 * x2 as u128;
+            #[allow(clippy::cast_possible_truncation, reason = "splitting into limbs on purpose")]
+            {
+                let low = p0.modulus as u128 * (tail as u64) as u128 + x0 as u128;
+                let high = p0.modulus as u128 * (tail >> LIMB_BITS) + (low >> LIMB_BITS);
+                c0 = low as u64;
+                c1 = high as u64;
+                c2 = (high >> LIMB_BITS) as u64;
+            }
+        }
+        let low = carry + c0 as u128;
+        #[allow(clippy::cast_possible_truncation, reason = "taking the lower limb on purpose")]
+        {out[i] = low as u64;}
+        carry = (low >> LIMB_BITS) + c1 as u128 + ((c2 as u128) << LIMB_BITS);
+    });
+}
@@ -0,0 +1,289 @@
+//! Multi-threaded versions of the heaviest operations, enabled by the `parallel` feature.
+//!
+//! Work is split into independent parts, each done by the very same sequential code on a scoped thread, and the parts are then combined.
+//! Splits are exact, so results are exactly the same as without the feature.
+//!
+//! Functions here accept the number of threads worth using (see [`threads`]), and fall back to the sequential code if there's only one.
+//! Callers should check if operands are long enough to be worth splitting first (see [`mul_worthwhile`], for example), since even finding the number of threads is not free.
+//! Spawned threads have much smaller stacks than the main one, so the larger buffers are allocated on the heap here. Fold workers run the sequential code on their own, so they are given a stack as large as the main thread usually has.
+
+extern crate std;
+
+use core::num::NonZero;
+use std::{
+    panic,
+    sync::{OnceLock, mpsc},
+    thread::{self, ScopedJoinHandle},
+    vec,
+    vec::Vec,
+};
+
+use super::{
+    const_ilog2, decimal, div,
+    limbs::{self, range, range_mut},
+    mul, ntt, wrap_product,
+};
+use crate::{LIMBS, u206265};
+
+/// Both operands should be at least this many limbs long for multiplication to be worth splitting between threads.
+///
+/// Spawning a thread takes some tens of microseconds, about as much as multiplying a couple of hundred limbs.
+const MUL_THRESHOLD: usize = 256;
+
+/// Numbers at least this many limbs long are worth splitting between threads for conversion into decimal.
+const DECIMAL_THRESHOLD: usize = 512;
+
+/// Folds of fewer items than this are done sequentially.
+const FOLD_THRESHOLD: usize = 32;
+
+/// First [`FOLD_THRESHOLD`] items should have at least this many significant limbs in total, for [`u206265::sum`](core::iter::Sum::sum) and [`u206265::product`](core::iter::Product::product) to be split between threads.
+///
+/// That's 64 limbs per item on average. Sending an item to a thread copies all of its limbs, which is about as long as adding up a couple of thousand limbs.
+pub(crate) const FOLD_LIMBS_THRESHOLD: usize = 64 * FOLD_THRESHOLD;
+
+/// Stack size of the fold workers. Sequential code keeps whole [`u206265`]s on the stack, so it's given as much as the main thread usually has.
+const WORKER_STACK_SIZE: usize = 8 << 20;
+
+/// Number of threads worth using, see [`thread::available_parallelism`].
+///
+/// Finding it means reading some system files on Linux, so it's only done once.
+pub(crate) fn threads() -> usize {
+    static THREADS: OnceLock<usize> = OnceLock::new();
+    *THREADS.get_or_init(|| thread::available_parallelism().map_or(1, NonZero::get))
+}
+
+/// Waits for the thread to finish, passing its panic on, if any.
+fn join<T>(handle: ScopedJoinHandle<'_, T>) -> T {
+    handle
+        .join()
+        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Checks if multiplication of ``lhs`` by ``rhs`` is worth splitting between threads.
+pub(crate) const fn mul_worthwhile(lhs: &u206265, rhs: &u206265) -> bool {
+    lhs.significant_limbs() >= MUL_THRESHOLD && rhs.significant_limbs() >= MUL_THRESHOLD
+}
+
+/// Multiplies ``lhs`` by ``rhs``. Same as [`const_mul`](super::const_mul), but large operands are multiplied on several threads.
+pub(crate) fn mul(lhs: &u206265, rhs: &u206265, threads: usize) -> (u206265, bool) {
+    let (Some(lhs_log), Some(rhs_log)) = (const_ilog2(lhs), const_ilog2(rhs)) else {
+        return (u206265::ZERO, false);
+    };
+    let lhs_limbs = lhs.limbs();
+    let rhs_limbs = rhs.limbs();
+
+    let mut result = [0u64; LIMBS];
+    mul_truncated(&mut result, lhs_limbs, rhs_limbs, threads);
+    wrap_product(result, lhs_log + rhs_log, lhs_limbs.len() + rhs_limbs.len())
+}
+
+/// Same as [`mul::mul_truncated`], but splits the work between threads.
+///
+/// Transform multiplication is split by primes, and the rest is split in a Karatsuba fashion, or in halves, if operands are unbalanced.
+fn mul_truncated(out: &mut [u64; LIMBS], lhs: &[u64], rhs: &[u64], threads: usize) {
+    if threads < 2 || lhs.len() < MUL_THRESHOLD || rhs.len() < MUL_THRESHOLD {
+        let mut scratch = vec![0u64; mul::truncated_scratch_len(lhs.len(), rhs.len())];
+        mul::mul_truncated(out, lhs, rhs, &mut scratch);
+        return;
+    }
+    let (lhs, rhs) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    if ntt::worthwhile(lhs.len(), rhs.len()) {
+        ntt_mul(out, lhs, rhs);
+        return;
+    }
+
+    let half = lhs.len() / 2;
+    let (lhs_low, lhs_high) = lhs.split_at(half);
+    let mut product = vec![0u64; lhs.len() + rhs.len()];
+    if 2 * rhs.len() <= lhs.len() {
+        // product is lhs_low * rhs + lhs_high * rhs * B^half
+        let [low, high] = products([(lhs_low, rhs), (lhs_high, rhs)]);
+        limbs::copy(&mut product, &low);
+        limbs::add_assign(range_mut(&mut product, half, lhs.len() + rhs.len()), &high);
+    } else {
+        // see `mul::karatsuba`
+        let (rhs_low, rhs_high) = rhs.split_at(half);
+        let mut lhs_sum = vec![0u64; lhs_high.len() + 1];
+        limbs::copy(&mut lhs_sum, lhs_high);
+        limbs::add_assign(&mut lhs_sum, lhs_low);
+        let mut rhs_sum = vec![0u64; rhs_low.len().max(rhs_high.len()) + 1];
+        limbs::copy(&mut rhs_sum, rhs_low);
+        limbs::add_assign(&mut rhs_sum, rhs_high);
+        let [low, high, mut middle] = products([
+            (lhs_low, rhs_low),
+            (lhs_high, rhs_high),
+            (&lhs_sum, &rhs_sum),
+        ]);
+        limbs::sub_assign(&mut middle, &low);
+        limbs::sub_assign(&mut middle, &high);
+        limbs::copy(&mut product, &low);
+        limbs::add_assign(
+            range_mut(&mut product, 2 * half, lhs.len() + rhs.len()),
+            &high,
+        );
+        limbs::add_assign(
+            range_mut(&mut product, half, lhs.len() + rhs.len()),
+            &middle,
+        );
+    }
+    limbs::copy(out, range(&product, 0, product.len().min(LIMBS)));
+}
+
+/// Multiplies each pair of operands on its own thread.
+fn products<const N: usize>(pairs: [(&[u64], &[u64]); N]) -> [Vec<u64>; N] {
+    thread::scope(|scope| {
+        pairs
+            .map(|(lhs, rhs)| {
+                scope.spawn(move || {
+                    let lhs = limbs::trim(lhs);
+                    let rhs = limbs::trim(rhs);
+                    let mut product = vec![0u64; lhs.len() + rhs.len()];
+                    let mut scratch = vec![0u64; mul::scratch_len(lhs.len().max(rhs.len()))];
+                    if !lhs.is_empty() && !rhs.is_empty() {
+                        mul::mul(&mut product, lhs, rhs, &mut scratch);
+                    }
+                    product
+                })
+            })
+            .map(join)
+    })
+}
+
+/// Same as [`ntt::mul`], but convolutions modulo different primes are found on their own threads.
+fn ntt_mul(out: &mut [u64; LIMBS], lhs: &[u64], rhs: &[u64]) {
+    let len = ntt::transform_len(lhs.len(), rhs.len());
+    let residues: [Vec<u64>; ntt::PRIMES_COUNT] = thread::scope(|scope| {
+        core::array::from_fn(|p| {
+            scope.spawn(move || {
+                let mut values = vec![0u64; len];
+                let mut scratch = vec![0u64; ntt::residues_scratch_len(len)];
+                ntt::residues(&mut values, p, lhs, rhs, &mut scratch);
+                values
+            })
+        })
+        .map(join)
+    });
+
+    limbs::zero(out);
+    let out_len = (lhs.len() + rhs.len()).min(LIMBS);
+    ntt::recombine(
+        range_mut(out, 0, out_len),
+        [&residues[0], &residues[1], &residues[2]],
+    );
+}
+
+/// Checks if conversion of ``value`` into decimal is worth splitting between threads.
+pub(crate) const fn to_chunks_worthwhile(value: &u206265) -> bool {
+    value.significant_limbs() >= DECIMAL_THRESHOLD
+}
+
+/// Same as [`decimal::to_chunks`], but upper halves of the larger numbers are converted on their own threads.
+pub(crate) fn to_chunks(out: &mut [u128; decimal::CHUNKS], value: &[u64], threads: usize) {
+    if threads < 2 {
+        decimal::to_chunks(out, value);
+        return;
+    }
+    let value = limbs::trim(value);
+    let chunks = decimal::chunk_count(value);
+    let mut powers = vec![0u64; decimal::POWERS_LEN];
+    decimal::powers(
+        (&mut powers[..])
+            .try_into()
+            .expect("Length is exactly right"),
+        chunks,
+    );
+
+    let (used, unused) = out.split_at_mut(chunks);
+    // every split doubles the number of threads
+    convert(
+        used,
+        value,
+        &powers,
+        threads.next_power_of_two().trailing_zeros(),
+    );
+    unused.fill(0);
+}
+
+/// Same as [`decimal::convert`], but the upper half is converted on its own thread, until `depth` reaches zero.
+fn convert(out: &mut [u128], value: &[u64], powers: &[u64], depth: u32) {
+    let value = limbs::trim(value);
+    if depth == 0 || value.len() < DECIMAL_THRESHOLD {
+        let mut scratch = vec![0u64; decimal::SCRATCH_LEN];
+        decimal::convert(out, value, powers, &mut scratch);
+        return;
+    }
+
+    let half = out.len() / 2;
+    let divisor = decimal::split_power(powers, half);
+    let mut quotient = vec![0u64; (value.len() + 1).saturating_sub(divisor.len())];
+    let mut remainder = vec![0u64; divisor.len()];
+    let mut scratch = vec![0u64; div::SCRATCH_LEN];
+    div::div_rem(&mut quotient, &mut remainder, value, divisor, &mut scratch);
+
+    let (lower, upper) = out.split_at_mut(half);
+    thread::scope(|scope| {
+        let handle = scope.spawn(|| convert(upper, &quotient, powers, depth - 1));
+        convert(lower, &remainder, powers, depth - 1);
+        join(handle);
+    });
+}
+
+/// Folds the items with `op`, distributing them between threads, and then folding the results of the threads.
+///
+/// `op` should be associative and commutative, with `identity` being its identity element. Then, no matter how the items are distributed, the result is the same as for [`Iterator::fold`].
+///
+/// Work is only split, if the first [`FOLD_THRESHOLD`] items have at least `min_limbs` significant limbs in total. Only then `threads` are found.
+pub(crate) fn fold<I: Iterator<Item = u206265>>(
+    mut iter: I,
+    identity: &u206265,
+    op: fn(&mut u206265, &u206265),
+    min_limbs: usize,
+    threads: impl FnOnce() -> usize,
+) -> u206265 {
+    let sequential = |acc: u206265, items: &mut dyn Iterator<Item = u206265>| {
+        items.fold(acc, |mut acc, item| {
+            op(&mut acc, &item);
+            acc
+        })
+    };
+    let head: Vec<u206265> = iter.by_ref().take(FOLD_THRESHOLD).collect();
+    let head_limbs: usize = head.iter().map(u206265::significant_limbs).sum();
+    if head.len() < FOLD_THRESHOLD || head_limbs < min_limbs {
+        return sequential(identity.const_clone(), &mut head.into_iter().chain(iter));
+    }
+    let threads = threads();
+    if threads < 2 {
+        return sequential(identity.const_clone(), &mut head.into_iter().chain(iter));
+    }
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                // items are large, so only a single one waits in the queue
+                let (sender, receiver) = mpsc::sync_channel::<u206265>(1);
+                let handle = thread::Builder::new()
+                    .stack_size(WORKER_STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        sequential(identity.const_clone(), &mut receiver.into_iter())
+                    })
+                    .expect("Failed to spawn a thread");
+                (sender, handle)
+            })
+            .collect();
+        for (item, (sender, _)) in head.into_iter().chain(iter).zip(workers.iter().cycle()) {
+            // receiver only hangs up if the thread panicked, and the panic is passed on by `join`
+            let _ = sender.send(item);
+        }
+        workers
+            .into_iter()
+            .fold(identity.const_clone(), |mut acc, (sender, handle)| {
+                drop(sender);
+                op(&mut acc, &join(handle));
+                acc
+            })
+    })
+}
@@ -0,0 +1,126 @@
+//! Formatting [`u206265`] in an arbitrary radix, into a caller-provided buffer.
+//!
+//! This doesn't need [`core::fmt`] or an allocator. Same as with parsing, digits are processed a machine word at a time: the integer is divided by the largest power of the radix fitting into a [`u64`], and digits are extracted from the remainder.
+
+use crate::{
+    const_ilog, const_ilog2,
+    pure_rust_impl::limbs::{self, LimbDivisor},
+    u206265,
+};
+
+/// Digits of all of the supported radixes. Letters are lowercase, same as with [`core::fmt::LowerHex`].
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Largest power of `radix` fitting into a [`u64`], along with its exponent.
+const fn chunk_power(radix: u64) -> (u64, u32) {
+    let mut power = radix;
+    let mut exp = 1;
+    while power <= u64::MAX / radix {
+        power *= radix;
+        exp += 1;
+    }
+    (power, exp)
+}
+
+impl u206265 {
+    /// Number of digits the integer has in the given radix. That's exactly the buffer length [`Self::write_str_radix`] needs.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// assert_eq!(u206265::from(255u8).formatted_len(16), 2);
+    /// assert_eq!(u206265::from(1000u16).formatted_len(10), 4);
+    /// assert_eq!(u206265::ZERO.formatted_len(2), 1);
+    /// ```
+    ///
+    /// Lengths in power-of-two radixes are found from [`Self::bit_length`] right away. Other radixes need [`const_ilog`], which is way slower.
+    ///
+    /// ### Panics
+    /// If ``radix`` is not in range ``2..=36``.
+    pub const fn formatted_len(&self, radix: u32) -> usize {
+        assert!(2 <= radix && radix <= 36, "Radix should be in range 2..=36");
+        let log = if radix.is_power_of_two() {
+            match const_ilog2(self) {
+                Some(log) => log / radix.ilog2(),
+                None => 0,
+            }
+        } else {
+            match const_ilog(self, &Self::from_u32(radix)) {
+                Some(log) => log,
+                None => 0,
+            }
+        };
+        log as usize + 1
+    }
+
+    /// Writes digits of the integer in the given radix into the start of ``buf``, most significant first. Letters are lowercase, and there's no prefix.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// let mut buf = [0u8; 8];
+    /// assert_eq!(u206265::from(255u8).write_str_radix(16, &mut buf), Some(2));
+    /// assert_eq!(&buf[..2], b"ff");
+    /// assert_eq!(u206265::from(255u8).write_str_radix(2, &mut buf[..7]), None);
+    /// ```
+    ///
+    /// ### Returns
+    /// Number of bytes written, or [`None`], if ``buf`` is too short, see [`Self::formatted_len`]. In the latter case, contents of ``buf`` are unspecified.
+    ///
+    /// ### Panics
+    /// If ``radix`` is not in range ``2..=36``.
+    pub const fn write_str_radix(&self, radix: u32, buf: &mut [u8]) -> Option<usize> {
+        assert!(2 <= radix && radix <= 36, "Radix should be in range 2..=36");
+        let radix = radix as u64;
+        let (power, exp) = chunk_power(radix);
+        let divisor = LimbDivisor::new(power);
+        let mut val = self.0;
+        let mut len = self.significant_limbs();
+        let mut written = 0;
+        // digits are written least significant first, and reversed afterwards
+        loop {
+            let mut chunk = divisor.div_rem_assign(limbs::range_mut(&mut val, 0, len));
+            len = limbs::significant_len(limbs::range(&val, 0, len));
+            let mut i = 0;
+            // chunks are padded with zeros, except for the upper-most one
+            while i < exp && !(len == 0 && chunk == 0 && written > 0) {
+                if written == buf.len() {
+                    return None;
+                }
+                buf[written] = DIGITS[(chunk % radix) as usize];
+                chunk /= radix;
+                written += 1;
+                i += 1;
+            }
+            if len == 0 {
+                break;
+            }
+        }
+        let mut i = 0;
+        while i < written / 2 {
+            let digit = buf[i];
+            buf[i] = buf[written - 1 - i];
+            buf[written - 1 - i] = digit;
+            i += 1;
+        }
+        Some(written)
+    }
+
+    /// Same as [`Self::write_str_radix`], but returns the written digits as a string slice.
+    ///
+    /// ```rust
+    /// # use not_too_many_arcseconds::u206265;
+    /// let mut buf = [0u8; 8];
+    /// assert_eq!(u206265::from(1295u16).to_str_radix(36, &mut buf), Some("zz"));
+    /// ```
+    ///
+    /// ### Panics
+    /// If ``radix`` is not in range ``2..=36``.
+    pub const fn to_str_radix<'buf>(&self, radix: u32, buf: &'buf mut [u8]) -> Option<&'buf str> {
+        let Some(written) = self.write_str_radix(radix, buf) else {
+            return None;
+        };
+        match core::str::from_utf8(buf.split_at(written).0) {
+            Ok(digits) => Some(digits),
+            Err(_) => panic!("Digits are ASCII"),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    LIMBS,
+    pure_rust_impl::{barrett, div},
+    u206265,
+};
+
+/// Precomputed reciprocal of a divisor, for dividing many numbers by the same [`u206265`].
+///
+/// Reciprocal is found once, with Newton's iteration. After that, division takes a couple of multiplications (Barrett reduction), instead of a long division.
+///
+/// ```rust
+/// # use not_too_many_arcseconds::{u206265, Reciprocal};
+/// let divisor = u206265::from(u128::MAX) * u206265::from(u128::MAX);
+/// let reciprocal = Reciprocal::new(&divisor).unwrap();
+///
+/// let value = &divisor * &u206265::from(5u8) + u206265::from(3u8);
+/// assert_eq!(reciprocal.div_rem(&value), (5u8.into(), 3u8.into()));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Reciprocal {
+    divisor: u206265,
+    /// $\lfloor 2^{64 L} / \text{divisor} \rfloor$, see [`barrett::INVERSE_LEN`]. Only computed for divisors long enough to benefit from it.
+    inverse: [u64; barrett::RECIPROCAL_LEN],
+}
+
+/// Only prints the divisor, since the inverse is as long as the divisor itself, and is of no interest.
+impl core::fmt::Debug for Reciprocal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Reciprocal")
+            .field("divisor", &self.divisor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Reciprocal {
+    /// Computes reciprocal of the `divisor`.
+    ///
+    /// ### Returns
+    /// [`Option::None`] if `divisor == 0`.
+    pub const fn new(divisor: &u206265) -> Option<Self> {
+        let len = divisor.significant_limbs();
+        if len == 0 {
+            return None;
+        }
+        let mut inverse = [0u64; barrett::RECIPROCAL_LEN];
+        if len >= barrett::DIVISOR_THRESHOLD {
+            let mut scratch = [0u64; barrett::INVERSE_SCRATCH_LEN];
+            barrett::inverse(&mut inverse, divisor.limbs(), &mut scratch);
+        }
+        Some(Self {
+            divisor: divisor.const_clone(),
+            inverse,
+        })
+    }
+
+    /// The divisor this is a reciprocal of.
+    #[inline]
+    pub const fn divisor(&self) -> &u206265 {
+        &self.divisor
+    }
+
+    /// Divides `lhs` by the divisor. Same as [`const_div_rem`](crate::const_div_rem), but the divisor can't be zero.
+    ///
+    /// ### Returns
+    /// ``(quotient, remainder)``
+    pub const fn div_rem(&self, lhs: &u206265) -> (u206265, u206265) {
+        let mut quotient = [0u64; LIMBS];
+        let mut remainder = [0u64; LIMBS];
+        let divisor_len = self.divisor.significant_limbs();
+        let quotient_len = lhs.significant_limbs().saturating_sub(divisor_len) + 1;
+        if divisor_len >= barrett::DIVISOR_THRESHOLD && quotient_len >= barrett::QUOTIENT_THRESHOLD
+        {
+            let mut scratch = [0u64; barrett::DIV_SCRATCH_LEN];
+            barrett::div_rem(
+                &mut quotient,
+                &mut remainder,
+                lhs.limbs(),
+                self.divisor.limbs(),
+                &self.inverse,
+                &mut scratch,
+            );
+        } else {
+            let mut scratch = [0u64; div::SCRATCH_LEN];
+            div::div_rem(
+                &mut quotient,
+                &mut remainder,
+                lhs.limbs(),
+                self.divisor.limbs(),
+                &mut scratch,
+            );
+        }
+        (
+            u206265::from_limbs_bounded(quotient, quotient_len),
+            u206265::from_limbs_bounded(remainder, divisor_len),
+        )
+    }
+
+    /// Divides `lhs` by the divisor. Same as [`const_div`](crate::const_div), but the divisor can't be zero.
+    #[inline]
+    pub const fn div(&self, lhs: &u206265) -> u206265 {
+        self.div_rem(lhs).0
+    }
+
+    /// Finds `lhs` modulo the divisor. Same as [`const_rem`](crate::const_rem), but the divisor can't be zero.
+    #[inline]
+    pub const fn rem(&self, lhs: &u206265) -> u206265 {
+        self.div_rem(lhs).1
+    }
+}
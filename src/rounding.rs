@@ -0,0 +1,115 @@
+//! Division with rounding, and other small helpers primitive integers have.
+//!
+//! These could be written with the operators, but that takes several temporary copies of the integer. Here, every one of them divides at most once, with [`const_div_rem`], and otherwise takes no more than two [`const_add`] or [`const_sub`] copies, with the rest done in-place.
+
+use core::cmp::Ordering;
+
+use crate::{
+    LIMBS, TOP_LIMB_MASK, const_add, const_add_assign, const_cmp, const_div_rem, const_shr_assign,
+    const_sub, u206265,
+};
+
+/// Rounding mode of [`u206265::div_round`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round down, same as the ``/`` operator
+    Floor,
+    /// Round up, same as [`u206265::div_ceil`]
+    Ceil,
+    /// Round to the nearest integer, and exact halves to the even one
+    HalfEven,
+}
+
+impl u206265 {
+    /// Finds the absolute difference between ``self`` and ``other``.
+    pub const fn abs_diff(&self, other: &Self) -> Self {
+        let (difference, borrow) = const_sub(self, other);
+        if borrow {
+            const_sub(other, self).0
+        } else {
+            difference
+        }
+    }
+
+    /// Finds ``self / rhs``, rounded up.
+    ///
+    /// ### Panics
+    /// If ``rhs == 0``.
+    #[inline]
+    pub const fn div_ceil(&self, rhs: &Self) -> Self {
+        self.div_round(rhs, Rounding::Ceil)
+    }
+
+    /// Finds ``self / rhs``, rounded according to ``rounding``.
+    ///
+    /// ### Panics
+    /// If ``rhs == 0``.
+    pub const fn div_round(&self, rhs: &Self, rounding: Rounding) -> Self {
+        let (mut quotient, remainder) = const_div_rem(self, rhs).expect("Division by zero");
+        if remainder.significant_limbs() == 0 {
+            return quotient;
+        }
+        let round_up = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => true,
+            Rounding::HalfEven => {
+                // comparing remainder with its complement is the same as comparing it with a half of the divisor, but can't overflow
+                let (complement, _) = const_sub(rhs, &remainder);
+                match const_cmp(&remainder, &complement) {
+                    Ordering::Less => false,
+                    Ordering::Equal => quotient.0[0] & 1 == 1,
+                    Ordering::Greater => true,
+                }
+            }
+        };
+        if round_up {
+            // quotient is less than self, so this can't overflow
+            const_add_assign(&mut quotient, &Self::ONE);
+        }
+        quotient
+    }
+
+    /// Finds the smallest multiple of ``rhs``, greater than or equal to ``self``, or [`None`], if ``rhs == 0`` or the multiple doesn't fit.
+    pub const fn checked_next_multiple_of(&self, rhs: &Self) -> Option<Self> {
+        let Some((_, remainder)) = const_div_rem(self, rhs) else {
+            return None;
+        };
+        if remainder.significant_limbs() == 0 {
+            return Some(self.const_clone());
+        }
+        let (mut result, _) = const_sub(rhs, &remainder);
+        if const_add_assign(&mut result, self) {
+            return None;
+        }
+        Some(result)
+    }
+
+    /// Finds the smallest multiple of ``rhs``, greater than or equal to ``self``.
+    ///
+    /// Overflow is handled the same way [`core::ops::Add`] does it.
+    ///
+    /// ### Panics
+    /// If ``rhs == 0``.
+    pub const fn next_multiple_of(&self, rhs: &Self) -> Self {
+        let (_, remainder) = const_div_rem(self, rhs).expect("Division by zero");
+        if remainder.significant_limbs() == 0 {
+            return self.const_clone();
+        }
+        let (mut result, _) = const_sub(rhs, &remainder);
+        let overflow = const_add_assign(&mut result, self);
+        debug_assert!(!overflow, "u206265 next_multiple_of overflow");
+        result
+    }
+
+    /// Finds the average of ``self`` and ``rhs``, rounded down. Unlike ``(self + rhs) / 2``, this never overflows.
+    pub const fn midpoint(&self, rhs: &Self) -> Self {
+        let (mut sum, carry) = const_add(self, rhs);
+        const_shr_assign(&mut sum, 1);
+        if carry {
+            // carry becomes the upper-most bit
+            sum.0[LIMBS - 1] |= TOP_LIMB_MASK & !(TOP_LIMB_MASK >> 1);
+            sum.recount(LIMBS);
+        }
+        sum
+    }
+}
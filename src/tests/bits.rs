@@ -0,0 +1,301 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::{BITS_U32, u206265};
+
+/// Number of bits [`u206265`] has above the ones of [`u128`]
+const EXTRA_BITS: u32 = BITS_U32 - u128::BITS;
+
+quickcheck! {
+    fn same_as_u128(val: u128) -> bool {
+        // arrange
+        let the_val = u206265::from(val);
+
+        // act, assert
+        the_val.count_ones() == val.count_ones()
+            && the_val.count_zeros() == val.count_zeros() + EXTRA_BITS
+            && the_val.leading_zeros() == val.leading_zeros() + EXTRA_BITS
+            && the_val.trailing_zeros() == if val == 0 { BITS_U32 } else { val.trailing_zeros() }
+            && the_val.leading_ones() == 0
+            && the_val.trailing_ones() == val.trailing_ones()
+            && the_val.bit_length() == u128::BITS - val.leading_zeros()
+    }
+}
+
+quickcheck! {
+    fn bits_of_inverted(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let the_inverted = !&the_val;
+
+        // act, assert
+        the_val.count_ones() + the_inverted.count_ones() == BITS_U32
+            && the_val.count_zeros() == the_inverted.count_ones()
+            && the_val.leading_zeros() == the_inverted.leading_ones()
+            && the_val.leading_ones() == the_inverted.leading_zeros()
+            && the_val.trailing_zeros() == the_inverted.trailing_ones()
+            && the_val.trailing_ones() == the_inverted.trailing_zeros()
+    }
+}
+
+quickcheck! {
+    fn bits_of_shifted(seed: u64, limbs: RangedUsize<1, 3223>, shift: RangedU32<0, 206_264>) -> bool {
+        // arrange
+        let shift = shift.get();
+        let the_val = random_u206265(seed, limbs.get());
+        let (the_shifted, _) = crate::const_shl(&the_val, shift);
+
+        // act, assert
+        // bits shifted in are zeros, and the ones shifted out are lost
+        if the_shifted == u206265::ZERO {
+            return the_val.trailing_zeros() + shift >= BITS_U32;
+        }
+        let fits = the_val.bit_length() + shift <= BITS_U32;
+        the_shifted.trailing_zeros() == the_val.trailing_zeros() + shift
+            && (!fits || the_shifted.bit_length() == the_val.bit_length() + shift)
+            && crate::const_ilog2(&the_shifted).map(|log| log + 1) == Some(the_shifted.bit_length())
+    }
+}
+
+#[test]
+fn bits_of_zero() {
+    // act, assert
+    assert_eq!(u206265::ZERO.count_ones(), 0);
+    assert_eq!(u206265::ZERO.count_zeros(), 206_265);
+    assert_eq!(u206265::ZERO.leading_zeros(), 206_265);
+    assert_eq!(u206265::ZERO.trailing_zeros(), 206_265);
+    assert_eq!(u206265::ZERO.leading_ones(), 0);
+    assert_eq!(u206265::ZERO.trailing_ones(), 0);
+    assert_eq!(u206265::ZERO.bit_length(), 0);
+}
+
+#[test]
+fn bits_of_max() {
+    // act, assert
+    assert_eq!(u206265::MAX.count_ones(), 206_265);
+    assert_eq!(u206265::MAX.count_zeros(), 0);
+    assert_eq!(u206265::MAX.leading_zeros(), 0);
+    assert_eq!(u206265::MAX.trailing_zeros(), 0);
+    assert_eq!(u206265::MAX.leading_ones(), 206_265);
+    assert_eq!(u206265::MAX.trailing_ones(), 206_265);
+    assert_eq!(u206265::MAX.bit_length(), 206_265);
+}
+
+#[test]
+fn bits_of_top_bit() {
+    // arrange
+    // the only bit of the upper-most byte
+    let mut bytes = [0u8; 25_784];
+    bytes[25_783] = 1;
+    let the_val = crate::create_bytes(bytes);
+
+    // act, assert
+    assert_eq!(the_val.count_ones(), 1);
+    assert_eq!(the_val.leading_zeros(), 0);
+    assert_eq!(the_val.trailing_zeros(), 206_264);
+    assert_eq!(the_val.leading_ones(), 1);
+    assert_eq!(the_val.trailing_ones(), 0);
+    assert_eq!(the_val.bit_length(), 206_265);
+}
+
+#[test]
+fn leading_ones_across_limbs() {
+    // arrange
+    // all ones, but bit 1000
+    let the_val = crate::const_bitxor(&u206265::MAX, &crate::const_shl(&u206265::ONE, 1000).0);
+
+    // act, assert
+    assert_eq!(the_val.leading_ones(), 206_264 - 1000);
+    assert_eq!(the_val.trailing_ones(), 1000);
+    assert_eq!(the_val.count_zeros(), 1);
+}
+
+/// Integer with a single bit set.
+fn bit(position: u32) -> u206265 {
+    crate::const_shl(&u206265::ONE, position).0
+}
+
+quickcheck! {
+    fn rotate_single_bit(position: RangedU32<0, 206_264>, n: u32) -> bool {
+        // arrange
+        let position = position.get();
+        let the_val = bit(position);
+        let left = (u64::from(position) + u64::from(n)) % u64::from(BITS_U32);
+        let right = (u64::from(position) + u64::from(BITS_U32) - u64::from(n % BITS_U32)) % u64::from(BITS_U32);
+
+        // act, assert
+        the_val.rotate_left(n) == bit(left.try_into().unwrap())
+            && the_val.rotate_right(n) == bit(right.try_into().unwrap())
+    }
+}
+
+quickcheck! {
+    fn rotate_back_and_forth(seed: u64, limbs: RangedUsize<0, 3223>, n: u32) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let the_rotated = the_val.rotate_left(n);
+
+        // assert
+        the_rotated.count_ones() == the_val.count_ones()
+            && the_rotated.rotate_right(n) == the_val
+            && the_rotated == the_val.rotate_right(BITS_U32 - n % BITS_U32)
+    }
+}
+
+quickcheck! {
+    fn rotate_composes(seed: u64, limbs: RangedUsize<0, 3223>, a: RangedU32<0, 206_264>, b: RangedU32<0, 206_264>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act, assert
+        the_val.rotate_left(a.get()).rotate_left(b.get()) == the_val.rotate_left((a.get() + b.get()) % BITS_U32)
+    }
+}
+
+#[test]
+fn rotate_top_bit() {
+    // act, assert
+    assert_eq!(bit(206_264).rotate_left(1), u206265::ONE);
+    assert_eq!(u206265::ONE.rotate_right(1), bit(206_264));
+    assert_eq!(u206265::MAX.rotate_left(12_345), u206265::MAX);
+}
+
+quickcheck! {
+    fn reverse_single_bit(position: RangedU32<0, 206_264>) -> bool {
+        // arrange
+        let position = position.get();
+
+        // act, assert
+        bit(position).reverse_bits() == bit(206_264 - position)
+    }
+}
+
+quickcheck! {
+    fn reverse_twice(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let the_reversed = the_val.reverse_bits();
+
+        // assert
+        the_reversed.count_ones() == the_val.count_ones()
+            && the_reversed.leading_zeros() == the_val.trailing_zeros()
+            && the_reversed.reverse_bits() == the_val
+    }
+}
+
+quickcheck! {
+    fn swap_bytes_same_as_u128(val: u128) -> bool {
+        // arrange
+        // lower-most byte becomes byte 25_782, the upper-most whole byte
+        let expected = crate::const_shl(&u206265::from(val.swap_bytes()), 206_264 - u128::BITS).0;
+
+        // act, assert
+        u206265::from(val).swap_bytes() == expected
+    }
+}
+
+quickcheck! {
+    fn swap_bytes_twice(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let the_swapped = the_val.swap_bytes();
+
+        // assert
+        the_swapped.count_ones() == the_val.count_ones()
+            && the_swapped.swap_bytes() == the_val
+    }
+}
+
+quickcheck! {
+    fn swap_bytes_bytes(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        // first big-endian byte holds the upper-most bit, and that one stays in place
+        let mut expected = the_val.to_be_bytes();
+        expected.rotate_left(1);
+
+        // act, assert
+        the_val.swap_bytes().to_le_bytes() == expected
+    }
+}
+
+#[test]
+fn swap_bytes_keeps_top_bit() {
+    // act, assert
+    assert_eq!(bit(206_264).swap_bytes(), bit(206_264));
+    assert_eq!(bit(206_263).swap_bytes(), bit(7));
+    assert_eq!(u206265::MAX.swap_bytes(), u206265::MAX);
+}
+
+quickcheck! {
+    fn power_of_two_same_as_u128(val: u128, shift: RangedU32<0, 127>) -> bool {
+        // arrange
+        // powers of two and their neighbours are way more interesting
+        let val = val >> shift.get();
+        let the_val = u206265::from(val);
+
+        // act, assert
+        the_val.is_power_of_two() == val.is_power_of_two()
+            && the_val.checked_next_power_of_two() == Some(val.checked_next_power_of_two().map_or(bit(128), u206265::from))
+            && the_val.prev_power_of_two() == if val == 0 { u206265::ZERO } else { u206265::from(1u128 << val.ilog2()) }
+    }
+}
+
+quickcheck! {
+    fn power_of_two_random(seed: u64, limbs: RangedUsize<1, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let log = the_val.bit_length() - 1;
+
+        // act, assert
+        // random values are never powers of two, but they are odd
+        !the_val.is_power_of_two()
+            && the_val.prev_power_of_two() == bit(log)
+            && the_val.checked_next_power_of_two() == (log < 206_264).then(|| bit(log + 1))
+    }
+}
+
+quickcheck! {
+    fn power_of_two_of_bit(position: RangedU32<0, 206_264>) -> bool {
+        // arrange
+        let the_val = bit(position.get());
+
+        // act, assert
+        the_val.is_power_of_two()
+            && the_val.next_power_of_two() == the_val
+            && the_val.prev_power_of_two() == the_val
+    }
+}
+
+#[test]
+fn power_of_two_edges() {
+    // act, assert
+    assert!(!u206265::ZERO.is_power_of_two());
+    assert_eq!(u206265::ZERO.next_power_of_two(), u206265::ONE);
+    assert_eq!(u206265::ZERO.prev_power_of_two(), u206265::ZERO);
+    assert!(!u206265::MAX.is_power_of_two());
+    assert_eq!(u206265::MAX.checked_next_power_of_two(), None);
+    assert_eq!(u206265::MAX.prev_power_of_two(), bit(206_264));
+    assert_eq!(
+        crate::const_add(&bit(206_263), &u206265::ONE)
+            .0
+            .checked_next_power_of_two(),
+        Some(bit(206_264))
+    );
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "u206265 next_power_of_two overflow")
+)]
+fn next_power_of_two_overflow() {
+    // act, assert
+    assert_eq!(u206265::MAX.next_power_of_two(), u206265::ZERO);
+}
@@ -0,0 +1,131 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::{BYTES, u206265, u206265FromSlice};
+
+quickcheck! {
+    fn same_as_u128(val: u128) -> bool {
+        // arrange
+        let the_val = u206265::from(val);
+
+        // act
+        let le = the_val.to_le_bytes();
+        let be = the_val.to_be_bytes();
+
+        // assert
+        le[..16] == val.to_le_bytes()
+            && le[16..].iter().all(|&byte| byte == 0)
+            && be[BYTES - 16..] == val.to_be_bytes()
+            && be[..BYTES - 16].iter().all(|&byte| byte == 0)
+            && u206265::from_be_bytes(val.to_be_bytes()) == the_val
+            && u206265::try_from_le_slice(&val.to_le_bytes()) == Ok(the_val.const_clone())
+            && u206265::try_from_be_slice(&val.to_be_bytes()) == Ok(the_val)
+    }
+}
+
+quickcheck! {
+    fn round_trip(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let le = the_val.to_le_bytes();
+        let be = the_val.to_be_bytes();
+
+        // assert
+        le.iter().rev().eq(be.iter())
+            && u206265::try_from_le_slice(&le) == Ok(the_val.const_clone())
+            && u206265::try_from_be_slice(&be) == Ok(the_val.const_clone())
+            && crate::create_bytes(le) == the_val
+            && u206265::from_be_bytes(be) == the_val
+    }
+}
+
+quickcheck! {
+    fn as_le_bytes_same_as_to_le_bytes(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act, assert
+        the_val.as_le_bytes() == &the_val.to_le_bytes()
+    }
+}
+
+quickcheck! {
+    fn significant_bytes_slice_after_ops(seed: u64, limbs: RangedUsize<0, 3223>, shift: RangedU32<0, 206_265>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let shift = shift.get();
+
+        // act
+        let shifted = the_val.const_clone() >> shift;
+        let results = [
+            crate::const_sub(&the_val, &shifted).0,
+            the_val.const_clone() << shift,
+            shifted,
+            !the_val,
+        ];
+
+        // assert
+        results.iter().all(|result| {
+            let le = result.to_le_bytes();
+            result.as_le_bytes() == &le
+                && result.significant_bytes_slice() == &le[..result.significant_bytes()]
+        })
+    }
+}
+
+#[test]
+fn from_slice_errors() {
+    // arrange
+    let too_long = [0u8; 25_785];
+    let mut le = [0u8; 25_784];
+    le[25_783] = 2;
+    let mut be = [0u8; 25_784];
+    be[0] = 2;
+
+    // act, assert
+    assert_eq!(
+        u206265::try_from_le_slice(&too_long),
+        Err(u206265FromSlice::TooLong { len: 25_785 })
+    );
+    assert_eq!(
+        u206265::try_from_be_slice(&too_long),
+        Err(u206265FromSlice::TooLong { len: 25_785 })
+    );
+    assert_eq!(
+        u206265::try_from_le_slice(&le),
+        Err(u206265FromSlice::Overflow)
+    );
+    assert_eq!(
+        u206265::try_from_be_slice(&be),
+        Err(u206265FromSlice::Overflow)
+    );
+}
+
+#[test]
+fn from_slice_max() {
+    // arrange
+    let mut le = [0xFFu8; 25_784];
+    le[25_783] = 1;
+    let mut be = [0xFFu8; 25_784];
+    be[0] = 1;
+
+    // act, assert
+    assert_eq!(u206265::try_from_le_slice(&le), Ok(u206265::MAX));
+    assert_eq!(u206265::try_from_be_slice(&be), Ok(u206265::MAX));
+    assert_eq!(u206265::MAX.to_le_bytes(), le);
+    assert_eq!(u206265::MAX.to_be_bytes(), be);
+    assert_eq!(u206265::try_from_le_slice(&[]), Ok(u206265::ZERO));
+}
+
+#[test]
+#[should_panic(expected = "Upper-most byte should contain at most 1!")]
+fn from_be_bytes_overflow() {
+    // arrange
+    let mut be = [0u8; 25_784];
+    be[0] = 2;
+
+    // act, assert
+    u206265::from_be_bytes(be);
+}
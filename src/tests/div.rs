@@ -1,4 +1,10 @@
-use crate::u206265;
+use alloc::vec::Vec;
+
+use deranged::{RangedU32, RangedUsize};
+use quickcheck::TestResult;
+
+use super::random_u206265;
+use crate::{LIMBS, u206265};
 
 quickcheck! {
     fn div_rem(lhs: u128, rhs: u128) -> bool {
@@ -24,3 +30,172 @@ quickcheck! {
         (div == div2) && (rem == rem2)
     }
 }
+
+/// Checks `lhs == quotient * rhs + remainder` and `remainder < rhs`.
+fn check_div_rem(lhs: &u206265, rhs: &u206265) -> bool {
+    let Some((the_div, the_rem)) = crate::const_div_rem(lhs, rhs) else {
+        return rhs == &u206265::ZERO;
+    };
+    let (product, mul_ov) = crate::const_mul(&the_div, rhs);
+    let (restored, add_ov) = crate::const_add(&product, &the_rem);
+    !mul_ov && !add_ov && &restored == lhs && &the_rem < rhs
+}
+
+quickcheck! {
+    fn div_rem_large(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<1, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+
+        // act, assert
+        check_div_rem(&the_lhs, &the_rhs)
+    }
+}
+
+/// Creates a number out of limbs that are likely to hit rare corrections of the quotient estimate.
+fn edgy_u206265(pattern: &[u8], shift: u32) -> u206265 {
+    const EDGES: [u64; 6] = [0, 1, u64::MAX, 1 << 63, u64::MAX >> 1, u64::MAX - 1];
+    let mut result = [0u64; LIMBS];
+    for (limb, &choice) in result.iter_mut().zip(pattern) {
+        *limb = EDGES[choice as usize % EDGES.len()];
+    }
+    crate::const_shr(&u206265::from_limbs(result), shift).0
+}
+
+quickcheck! {
+    fn div_rem_edgy(lhs: Vec<u8>, rhs: Vec<u8>, lhs_shift: RangedU32<0, 64>, rhs_shift: RangedU32<0, 64>) -> bool {
+        // arrange
+        let the_lhs = edgy_u206265(&lhs, lhs_shift.get());
+        let the_rhs = edgy_u206265(&rhs, rhs_shift.get());
+
+        // act, assert
+        check_div_rem(&the_lhs, &the_rhs)
+    }
+}
+
+#[test]
+fn div_rem_add_back() {
+    // arrange
+    // quotient limb estimate is one too big here, and only the full multiplication finds that out
+    let the_lhs = u206265::from_limbs({
+        let mut limbs = [0u64; LIMBS];
+        limbs[..4].copy_from_slice(&[0, 0, 1 << 63, u64::MAX >> 1]);
+        limbs
+    });
+    let the_rhs = u206265::from_limbs({
+        let mut limbs = [0u64; LIMBS];
+        limbs[..3].copy_from_slice(&[1, 0, 1 << 63]);
+        limbs
+    });
+
+    // act, assert
+    assert!(check_div_rem(&the_lhs, &the_rhs));
+}
+
+#[test]
+fn div_max_by_max() {
+    // act
+    let the_result = crate::const_div_rem(&u206265::MAX, &u206265::MAX);
+
+    // assert
+    assert_eq!(the_result, Some((u206265::ONE, u206265::ZERO)));
+}
+
+quickcheck! {
+    fn div_rem_u64(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs: u64) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let expected = crate::const_div_rem(&the_lhs, &u206265::from(rhs))
+            .map(|(div, rem)| (div, u64::try_from(rem).unwrap()));
+
+        // act
+        let the_result = crate::const_div_rem_u64(&the_lhs, rhs);
+
+        // assert
+        the_result == expected
+    }
+}
+
+quickcheck! {
+    fn rem_u64(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs: u64) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let expected = crate::const_div_rem_u64(&the_lhs, rhs).map(|(_, rem)| rem);
+
+        // act
+        let the_rem = crate::const_rem_u64(&the_lhs, rhs);
+
+        // assert
+        the_rem == expected
+    }
+}
+
+quickcheck! {
+    fn div_u64_assign(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs: u64) -> TestResult {
+        if rhs == 0 {
+            return TestResult::discard();
+        }
+        // arrange
+        let mut the_lhs = random_u206265(seed, lhs_limbs.get());
+        let expected = crate::const_div_rem_u64(&the_lhs, rhs).unwrap();
+
+        // act
+        let the_rem = crate::const_div_u64_assign(&mut the_lhs, rhs);
+
+        // assert
+        TestResult::from_bool((the_lhs, the_rem) == expected)
+    }
+}
+
+macro_rules! special_div_rem_u64 {
+    ($rhs:expr, $name:ident) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_div_rem_u64_by_ $name>]() {
+                // arrange
+                let rhs: u64 = $rhs;
+                let expected = crate::const_div_rem(&u206265::MAX, &u206265::from(rhs))
+                    .map(|(div, rem)| (div, u64::try_from(rem).unwrap()));
+
+                // act
+                let the_result = crate::const_div_rem_u64(&u206265::MAX, rhs);
+
+                // assert
+                assert_eq!(the_result, expected);
+            }
+        }
+    };
+}
+
+special_div_rem_u64!(0, zero);
+special_div_rem_u64!(1, one);
+special_div_rem_u64!(3, three);
+special_div_rem_u64!(10_000_000_000_000_000_000, ten_19);
+special_div_rem_u64!(1 << 63, high_bit);
+special_div_rem_u64!(u64::MAX, max);
+
+#[test]
+#[cfg_attr(
+    feature = "copy",
+    allow(
+        clippy::op_ref,
+        clippy::clone_on_copy,
+        reason = "all forms of the operator are tested"
+    )
+)]
+fn div_rem_u64_ops() {
+    // arrange
+    let the_lhs = u206265::from(u128::MAX);
+    let rhs = 1_000_000_007u64;
+
+    // act, assert
+    let expected_div = u206265::from(u128::MAX / u128::from(rhs));
+    let expected_rem = u64::try_from(u128::MAX % u128::from(rhs)).unwrap();
+    assert_eq!(&the_lhs / rhs, expected_div);
+    assert_eq!(the_lhs.clone() / rhs, expected_div);
+    assert_eq!(&the_lhs % rhs, expected_rem);
+    assert_eq!(the_lhs.clone() % rhs, expected_rem);
+    let mut the_lhs = the_lhs;
+    the_lhs /= rhs;
+    assert_eq!(the_lhs, expected_div);
+}
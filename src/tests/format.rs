@@ -1,3 +1,8 @@
+use alloc::{string::String, vec::Vec};
+
+use deranged::RangedUsize;
+
+use super::random_u206265;
 use crate::u206265;
 
 quickcheck! {
@@ -28,6 +33,52 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn binary(val: u128) -> bool {
+        format!("{:b}", val) == format!("{:b}", u206265::from(val))
+    }
+}
+
+quickcheck! {
+    fn octal(val: u128) -> bool {
+        format!("{:o}", val) == format!("{:o}", u206265::from(val))
+    }
+}
+
+/// Digits of the integer in base $2^\text{bits}$, read bit-by-bit from its bytes.
+fn digits_reference(val: &u206265, bits: usize) -> String {
+    let bytes = val.to_le_bytes();
+    let bit = |i: usize| bytes.get(i / 8).map_or(0, |byte| (byte >> (i % 8)) & 1);
+    let len = usize::try_from(val.bit_length()).unwrap().div_ceil(bits);
+    let digits = (0..len)
+        .rev()
+        .map(|digit| (0..bits).map(|j| bit(digit * bits + j) << j).sum::<u8>())
+        .map(|digit| char::from_digit(digit.into(), 1 << bits).unwrap())
+        .collect::<String>();
+    if digits.is_empty() {
+        String::from("0")
+    } else {
+        digits
+    }
+}
+
+quickcheck! {
+    fn binary_octal_large(seed: u64, limbs: RangedUsize<0, 400>) -> bool {
+        let val = random_u206265(seed, limbs.get());
+        digits_reference(&val, 1) == format!("{:b}", val)
+            && digits_reference(&val, 3) == format!("{:o}", val)
+    }
+}
+
+#[test]
+fn binary_octal_of_max() {
+    // 206_265 = 3 * 68_755, so the upper-most octal digit is a full one
+    assert_eq!(format!("{:b}", u206265::MAX), "1".repeat(206_265));
+    assert_eq!(format!("{:o}", u206265::MAX), "7".repeat(68_755));
+    assert_eq!(format!("{:b}", u206265::ZERO), "0");
+    assert_eq!(format!("{:o}", u206265::ZERO), "0");
+}
+
 quickcheck! {
     fn display(val: u128) -> bool {
         format!("{}", val) == format!("{}", u206265::from(val))
@@ -57,3 +108,184 @@ fn special_display_for_max() {
     let val: u128 = u128::MAX;
     assert_eq!(format!("{}", val), format!("{}", u206265::from(val)));
 }
+
+/// Straightforward decimal formatting, dividing by $10^{38}$ over and over.
+fn display_reference(val: &u206265) -> String {
+    let ten_38 = u206265::from(10u128.pow(38));
+    let mut chunks = Vec::new();
+    let mut val = val.const_clone();
+    loop {
+        let (div, rem) = crate::const_div_rem(&val, &ten_38).unwrap();
+        chunks.push(u128::try_from(rem).unwrap());
+        val = div;
+        if val == u206265::ZERO {
+            break;
+        }
+    }
+    let mut result = format!("{}", chunks.pop().unwrap());
+    for chunk in chunks.into_iter().rev() {
+        result += &format!("{chunk:038}");
+    }
+    result
+}
+
+quickcheck! {
+    fn display_large(seed: u64, limbs: RangedUsize<0, 100>) -> bool {
+        let val = random_u206265(seed, limbs.get());
+        display_reference(&val) == format!("{}", val)
+    }
+}
+
+/// $10^{38 \cdot chunks}$, plus `offset`.
+fn ten_38_power(chunks: usize, offset: i8) -> u206265 {
+    let ten_38 = u206265::from(10u128.pow(38));
+    let mut val = u206265::ONE;
+    for _ in 0..chunks {
+        val *= ten_38.const_clone();
+    }
+    if offset < 0 {
+        val - u206265::from(offset.unsigned_abs())
+    } else {
+        val + u206265::from(offset.unsigned_abs())
+    }
+}
+
+macro_rules! special_display_ten_38_power {
+    ($chunks:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_display_for_ten_38_power_ $chunks>]() {
+                for offset in [-1, 0, 1] {
+                    let val = ten_38_power($chunks, offset);
+                    assert_eq!(display_reference(&val), format!("{}", val));
+                }
+            }
+        }
+    };
+}
+
+special_display_ten_38_power!(1);
+special_display_ten_38_power!(2);
+special_display_ten_38_power!(512);
+special_display_ten_38_power!(1024);
+special_display_ten_38_power!(1025);
+special_display_ten_38_power!(1633);
+
+#[test]
+fn special_display_for_u206265_max() {
+    let formatted = format!("{}", u206265::MAX);
+    assert_eq!(formatted.len(), 62_092);
+    assert_eq!(display_reference(&u206265::MAX), formatted);
+}
+
+/// Compares formatting of [`u128`] and [`u206265`] with the given flags, for every formatting trait.
+macro_rules! format_flags {
+    ($name:ident, $flags:literal) => {
+        quickcheck! {
+            fn $name(val: u128, width: RangedUsize<0, 150>) -> bool {
+                let width = width.get();
+                let the_val = u206265::from(val);
+                format!(concat!("{:", $flags, "width$}"), val, width = width) == format!(concat!("{:", $flags, "width$}"), the_val, width = width)
+                    && format!(concat!("{:", $flags, "width$x}"), val, width = width) == format!(concat!("{:", $flags, "width$x}"), the_val, width = width)
+                    && format!(concat!("{:", $flags, "width$X}"), val, width = width) == format!(concat!("{:", $flags, "width$X}"), the_val, width = width)
+                    && format!(concat!("{:", $flags, "width$b}"), val, width = width) == format!(concat!("{:", $flags, "width$b}"), the_val, width = width)
+                    && format!(concat!("{:", $flags, "width$o}"), val, width = width) == format!(concat!("{:", $flags, "width$o}"), the_val, width = width)
+            }
+        }
+    };
+}
+
+format_flags!(flags_none, "");
+format_flags!(flags_plus, "+");
+format_flags!(flags_alternate, "#");
+format_flags!(flags_plus_alternate, "+#");
+format_flags!(flags_zero, "0");
+format_flags!(flags_alternate_zero, "#0");
+format_flags!(flags_plus_alternate_zero, "+#0");
+format_flags!(flags_left, "<");
+format_flags!(flags_right, ">");
+format_flags!(flags_center, "^");
+format_flags!(flags_fill_center, "*^");
+format_flags!(flags_fill_left_alternate, "_<#");
+format_flags!(flags_right_plus, ">+");
+format_flags!(flags_fill_center_minus_alternate, "ж^-#");
+format_flags!(flags_fill_left_zero, "x<0");
+format_flags!(flags_center_plus_alternate_zero, "^+#0");
+
+quickcheck! {
+    fn flags_large(seed: u64, limbs: RangedUsize<0, 100>, width: RangedUsize<0, 10_000>) -> bool {
+        // arrange
+        let width = width.get();
+        let val = random_u206265(seed, limbs.get());
+        let digits = format!("{val:x}");
+        let padding = width.saturating_sub(digits.len() + 2);
+
+        // act, assert
+        format!("{val:#width$x}") == format!("{}0x{digits}", " ".repeat(padding))
+            && format!("{val:#0width$x}") == format!("0x{}{digits}", "0".repeat(padding))
+            && format!("{val:_<+width$}").trim_end_matches('_') == format!("+{val}")
+            && format!("{val:_<+width$}").len() == width.max(format!("{val}").len() + 1)
+    }
+}
+
+#[test]
+fn flags_of_max() {
+    // arrange
+    let digits = format!("{:x}", u206265::MAX);
+
+    // act, assert
+    assert_eq!(format!("{:#x}", u206265::MAX), format!("0x{digits}"));
+    assert_eq!(
+        format!("{:>51600x}", u206265::MAX),
+        format!("{}{digits}", " ".repeat(51_600 - digits.len()))
+    );
+    assert_eq!(format!("{:+#b}", u206265::ZERO), "+0b0");
+    assert_eq!(format!("{:^5}", u206265::ZERO), "  0  ");
+}
+
+quickcheck! {
+    fn debug_small(val: u128) -> bool {
+        format!("{:?}", u206265::from(val)) == format!("u206265({val})")
+            && format!("{:x?}", u206265::from(val)) == format!("u206265({val:x?})")
+            && format!("{:X?}", u206265::from(val)) == format!("u206265({val:X?})")
+            && format!("{:08x?}", u206265::from(val)) == format!("u206265({val:08x?})")
+    }
+}
+
+quickcheck! {
+    fn debug_large(seed: u64, limbs: RangedUsize<3, 3223>) -> bool {
+        // arrange
+        let val = random_u206265(seed, limbs.get());
+        let digits = format!("{val:x}");
+
+        // act, assert
+        format!("{val:?}")
+            == format!(
+                "u206265(0x{}…{} ({} bits))",
+                &digits[..16],
+                &digits[digits.len() - 16..],
+                val.bit_length()
+            )
+            && format!("{val:x?}") == format!("{val:?}")
+    }
+}
+
+#[test]
+fn debug_alternate() {
+    // arrange
+    let val = u206265::from(u64::MAX) + u206265::ONE;
+
+    // act, assert
+    assert_eq!(
+        format!("{val:#?}"),
+        "u206265 {\n    value: 18446744073709551616,\n    bits: 65,\n    significant_bytes: 9,\n    significant_limbs: 2,\n}"
+    );
+    assert_eq!(
+        format!("{:#x?}", u206265::ZERO),
+        "u206265 {\n    value: 0x0,\n    bits: 0x0,\n    significant_bytes: 0x0,\n    significant_limbs: 0x0,\n}"
+    );
+    assert_eq!(
+        format!("{:?}", crate::Reciprocal::new(&val).unwrap()),
+        "Reciprocal { divisor: u206265(18446744073709551616), .. }"
+    );
+}
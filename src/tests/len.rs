@@ -0,0 +1,77 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::{pure_rust_impl::limbs, u206265};
+
+/// Checks the cached number of significant limbs against the limbs themselves.
+fn len_is_valid(value: &u206265) -> bool {
+    value.significant_limbs() == limbs::significant_len(&value.0)
+}
+
+quickcheck! {
+    fn len_arithmetic(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+
+        // act
+        let the_results = [
+            crate::const_add(&the_lhs, &the_rhs).0,
+            crate::const_sub(&the_lhs, &the_rhs).0,
+            crate::const_sub(&the_rhs, &the_lhs).0,
+            crate::const_mul(&the_lhs, &the_rhs).0,
+            crate::const_sqr(&the_lhs).0,
+            crate::const_div(&the_lhs, &the_rhs).unwrap_or(u206265::ZERO),
+            crate::const_rem(&the_lhs, &the_rhs).unwrap_or(u206265::ZERO),
+            crate::const_mul_u64(&the_lhs, seed).0,
+            crate::const_div_u64(&the_lhs, seed).unwrap_or(u206265::ZERO),
+        ];
+
+        // assert
+        the_results.iter().all(len_is_valid)
+    }
+}
+
+quickcheck! {
+    fn len_bitwise(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>, shift: RangedU32<0, 206_300>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+
+        // act
+        let the_results = [
+            crate::const_bitand(&the_lhs, &the_rhs),
+            crate::const_bitor(&the_lhs, &the_rhs),
+            crate::const_bitxor(&the_lhs, &the_rhs),
+            crate::const_bitxor(&the_lhs, &the_lhs),
+            !&the_lhs,
+            crate::const_shl(&the_lhs, shift.get()).0,
+            crate::const_shr(&the_lhs, shift.get()).0,
+        ];
+
+        // assert
+        the_results.iter().all(len_is_valid)
+    }
+}
+
+#[test]
+fn len_of_constants() {
+    // act, assert
+    assert!(len_is_valid(&u206265::ZERO));
+    assert!(len_is_valid(&u206265::ONE));
+    assert!(len_is_valid(&u206265::MAX));
+    assert!(len_is_valid(&u206265::MAX_U128));
+    assert!(len_is_valid(&crate::create_bytes([0u8; 25_784])));
+}
+
+#[test]
+fn len_after_max_overflow() {
+    // act
+    let (the_sum, _) = crate::const_add(&u206265::MAX, &u206265::ONE);
+    let (the_shift, _) = crate::const_shl(&u206265::MAX, 64);
+
+    // assert
+    assert!(len_is_valid(&the_sum));
+    assert!(len_is_valid(&the_shift));
+    assert_eq!(the_sum, u206265::ZERO);
+}
@@ -24,3 +24,5 @@ test_singificant_bytes!(1u8, 1);
 test_singificant_bytes!(255u8, 1);
 test_singificant_bytes!(0x00FFu16, 1);
 test_singificant_bytes!(0x01FFu16, 2);
+test_singificant_bytes!(0xFFFF_FFFF_FFFF_FFFFu64, 8);
+test_singificant_bytes!(0x01_0000_0000_0000_0000u128, 9);
@@ -1,14 +1,52 @@
 mod from_into;
 
 mod add;
+mod bits;
 mod bitwise;
+mod bytes;
 mod cmp;
 mod div;
 mod format;
+mod len;
 mod log;
 mod misc;
 mod mul;
 mod not;
+mod overflow;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod parse;
+mod perfect_power;
+mod radix;
+mod reciprocal;
+mod root;
+mod rounding;
 mod shl;
 mod shr;
+mod sqr;
+mod sqrt;
 mod sub;
+
+/// Creates a pseudo-random [`u206265`](crate::u206265) with exactly `limbs` significant limbs.
+///
+/// Quickcheck's own generators are too small to reach the interesting sizes, so the big values are expanded from a seed.
+fn random_u206265(seed: u64, limbs: usize) -> crate::u206265 {
+    // splitmix64
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    let mut result = [0u64; crate::LIMBS];
+    for limb in &mut result[..limbs] {
+        *limb = next();
+    }
+    if limbs > 0 {
+        result[limbs - 1] |= 1;
+    }
+    result[crate::LIMBS - 1] &= crate::TOP_LIMB_MASK;
+    crate::u206265::from_limbs(result)
+}
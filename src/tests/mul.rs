@@ -1,4 +1,9 @@
-use crate::{u206265, u206265ToUnsigned};
+use alloc::vec;
+
+use deranged::RangedUsize;
+
+use super::random_u206265;
+use crate::{BITS, LIMBS, TOP_LIMB_MASK, u206265, u206265ToUnsigned};
 
 quickcheck! {
     fn mul(lhs: u128, rhs: u128) -> bool {
@@ -47,3 +52,181 @@ quickcheck! {
         }
     }
 }
+
+#[test]
+fn mul_overflow_past_last_limb() {
+    // arrange
+    let lhs = crate::const_shl(&u206265::ONE, 103_136).0;
+    let rhs = crate::const_shl(&u206265::ONE, 103_136).0;
+
+    // act
+    let (the_mul, the_ov) = crate::const_mul(&lhs, &rhs);
+
+    // assert
+    assert!(the_ov, "2^206272 does not fit into u206265");
+    assert_eq!(the_mul, u206265::ZERO);
+}
+
+#[test]
+fn mul_max_by_max() {
+    // act
+    let (the_mul, the_ov) = crate::const_mul(&u206265::MAX, &u206265::MAX);
+
+    // assert
+    assert!(the_ov, "MAX * MAX should overflow");
+    assert_eq!(the_mul, u206265::ONE);
+}
+
+fn schoolbook(lhs: &u206265, rhs: &u206265) -> (u206265, bool) {
+    let mut result = [0u64; LIMBS];
+    crate::pure_rust_impl::mul::schoolbook(&mut result, lhs.limbs(), rhs.limbs());
+    let (Some(lhs_log), Some(rhs_log)) = (crate::const_ilog2(lhs), crate::const_ilog2(rhs)) else {
+        return (u206265::ZERO, false);
+    };
+    let overflow = (lhs_log + rhs_log) as usize >= BITS || result[LIMBS - 1] > TOP_LIMB_MASK;
+    result[LIMBS - 1] &= TOP_LIMB_MASK;
+    (u206265::from_limbs(result), overflow)
+}
+
+quickcheck! {
+    fn mul_large(seed: u64, lhs_limbs: RangedUsize<1, 600>, rhs_limbs: RangedUsize<1, 600>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let expected = schoolbook(&the_lhs, &the_rhs);
+
+        // act
+        let the_mul = crate::const_mul(&the_lhs, &the_rhs);
+
+        // assert
+        the_mul == expected
+    }
+}
+
+macro_rules! special_mul_large {
+    ($lhs_limbs:literal, $rhs_limbs:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_mul_large_ $lhs_limbs _by_ $rhs_limbs>]() {
+                // arrange
+                let the_lhs = random_u206265($lhs_limbs, $lhs_limbs);
+                let the_rhs = random_u206265($rhs_limbs, $rhs_limbs);
+                let expected = schoolbook(&the_lhs, &the_rhs);
+
+                // act
+                let the_mul = crate::const_mul(&the_lhs, &the_rhs);
+
+                // assert
+                assert_eq!(the_mul, expected);
+            }
+        }
+    };
+}
+
+special_mul_large!(32, 32);
+special_mul_large!(128, 128);
+special_mul_large!(1000, 33);
+special_mul_large!(1611, 1612);
+special_mul_large!(3000, 2000);
+special_mul_large!(3223, 3223);
+special_mul_large!(1900, 2000);
+
+fn ntt_matches_schoolbook(lhs: &[u64], rhs: &[u64]) -> bool {
+    let mut expected = [0u64; 2 * LIMBS];
+    let mut the_mul = [0u64; 2 * LIMBS];
+    let product_len = lhs.len() + rhs.len();
+    crate::pure_rust_impl::mul::schoolbook(&mut expected[..product_len], lhs, rhs);
+
+    let mut scratch = vec![0u64; crate::pure_rust_impl::ntt::scratch_len(lhs.len(), rhs.len())];
+    crate::pure_rust_impl::ntt::mul(&mut the_mul[..product_len], lhs, rhs, &mut scratch);
+
+    the_mul == expected
+}
+
+quickcheck! {
+    fn ntt(seed: u64, lhs_limbs: RangedUsize<1, 600>, rhs_limbs: RangedUsize<1, 600>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+
+        // act, assert
+        ntt_matches_schoolbook(the_lhs.limbs(), the_rhs.limbs())
+    }
+}
+
+#[test]
+fn ntt_all_ones() {
+    // arrange
+    // largest possible coefficients
+    let ones = [u64::MAX; LIMBS];
+
+    // act, assert
+    assert!(ntt_matches_schoolbook(&ones, &ones));
+}
+
+#[test]
+fn ntt_single_limb() {
+    // act, assert
+    assert!(ntt_matches_schoolbook(&[u64::MAX], &[u64::MAX]));
+    assert!(ntt_matches_schoolbook(&[3], &[0, 0, 5]));
+}
+
+quickcheck! {
+    fn mul_u64(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs: u64) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let expected = crate::const_mul(&the_lhs, &u206265::from(rhs));
+
+        // act
+        let the_mul = crate::const_mul_u64(&the_lhs, rhs);
+
+        // assert
+        the_mul == expected
+    }
+}
+
+quickcheck! {
+    fn mul_u64_assign(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs: u64) -> bool {
+        // arrange
+        let mut the_lhs = random_u206265(seed, lhs_limbs.get());
+        let expected = crate::const_mul(&the_lhs, &u206265::from(rhs));
+
+        // act
+        let the_ov = crate::const_mul_u64_assign(&mut the_lhs, rhs);
+
+        // assert
+        (the_lhs, the_ov) == expected
+    }
+}
+
+#[test]
+fn mul_u64_overflow_at_top_limb() {
+    // act
+    let (the_mul, the_ov) = crate::const_mul_u64(&u206265::MAX, 2);
+
+    // assert
+    assert!(the_ov, "MAX * 2 should overflow");
+    assert_eq!(the_mul, u206265::MAX - u206265::ONE);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "copy",
+    allow(
+        clippy::op_ref,
+        clippy::clone_on_copy,
+        reason = "all forms of the operator are tested"
+    )
+)]
+fn mul_u64_op() {
+    // arrange
+    let the_lhs = u206265::from(u64::MAX);
+
+    // act, assert
+    let expected = u206265::from(u128::from(u64::MAX) * 3);
+    assert_eq!(&the_lhs * 3, expected);
+    assert_eq!(the_lhs.clone() * 3, expected);
+    let mut the_lhs = the_lhs;
+    the_lhs *= 3;
+    assert_eq!(the_lhs, expected);
+}
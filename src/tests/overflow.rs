@@ -0,0 +1,326 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::{BITS_U32, u206265};
+
+quickcheck! {
+    fn same_as_u128(lhs: u128, rhs: u128) -> bool {
+        // arrange
+        let the_lhs = u206265::from(lhs);
+        let the_rhs = u206265::from(rhs);
+
+        // act, assert
+        // these can't overflow for values coming from u128
+        the_lhs.checked_sub(&the_rhs) == lhs.checked_sub(rhs).map(u206265::from)
+            && the_lhs.saturating_sub(&the_rhs) == u206265::from(lhs.saturating_sub(rhs))
+            && the_lhs.checked_div(&the_rhs) == lhs.checked_div(rhs).map(u206265::from)
+            && the_lhs.checked_rem(&the_rhs) == lhs.checked_rem(rhs).map(u206265::from)
+    }
+}
+
+quickcheck! {
+    fn add_family(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let (sum, overflow) = crate::const_add(&the_lhs, &the_rhs);
+
+        // act, assert
+        the_lhs.overflowing_add(&the_rhs) == (sum.const_clone(), overflow)
+            && the_lhs.wrapping_add(&the_rhs) == sum.const_clone()
+            && the_lhs.checked_add(&the_rhs) == (!overflow).then_some(sum.const_clone())
+            && the_lhs.saturating_add(&the_rhs) == if overflow { u206265::MAX } else { sum }
+    }
+}
+
+quickcheck! {
+    fn sub_family(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let (difference, overflow) = crate::const_sub(&the_lhs, &the_rhs);
+
+        // act, assert
+        the_lhs.overflowing_sub(&the_rhs) == (difference.const_clone(), overflow)
+            && the_lhs.wrapping_sub(&the_rhs) == difference.const_clone()
+            && the_lhs.checked_sub(&the_rhs) == (!overflow).then_some(difference.const_clone())
+            && the_lhs.saturating_sub(&the_rhs) == if overflow { u206265::ZERO } else { difference }
+    }
+}
+
+quickcheck! {
+    fn mul_family(seed: u64, lhs_limbs: RangedUsize<0, 1700>, rhs_limbs: RangedUsize<0, 1700>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let (product, overflow) = crate::const_mul(&the_lhs, &the_rhs);
+
+        // act, assert
+        the_lhs.overflowing_mul(&the_rhs) == (product.const_clone(), overflow)
+            && the_lhs.wrapping_mul(&the_rhs) == product.const_clone()
+            && the_lhs.checked_mul(&the_rhs) == (!overflow).then_some(product.const_clone())
+            && the_lhs.saturating_mul(&the_rhs) == if overflow { u206265::MAX } else { product }
+    }
+}
+
+#[test]
+fn max_plus_one() {
+    // act, assert
+    assert_eq!(u206265::MAX.checked_add(&u206265::ONE), None);
+    assert_eq!(u206265::MAX.wrapping_add(&u206265::ONE), u206265::ZERO);
+    assert_eq!(
+        u206265::MAX.overflowing_add(&u206265::ONE),
+        (u206265::ZERO, true)
+    );
+    assert_eq!(u206265::MAX.saturating_add(&u206265::ONE), u206265::MAX);
+}
+
+#[test]
+fn zero_minus_one() {
+    // act, assert
+    assert_eq!(u206265::ZERO.checked_sub(&u206265::ONE), None);
+    assert_eq!(u206265::ZERO.wrapping_sub(&u206265::ONE), u206265::MAX);
+    assert_eq!(
+        u206265::ZERO.overflowing_sub(&u206265::ONE),
+        (u206265::MAX, true)
+    );
+    assert_eq!(u206265::ZERO.saturating_sub(&u206265::ONE), u206265::ZERO);
+}
+
+#[test]
+fn max_times_two() {
+    // arrange
+    let two = u206265::from(2u8);
+
+    // act, assert
+    assert_eq!(u206265::MAX.checked_mul(&two), None);
+    assert_eq!(u206265::MAX.wrapping_mul(&two), u206265::MAX - u206265::ONE);
+    assert_eq!(u206265::MAX.saturating_mul(&two), u206265::MAX);
+}
+
+quickcheck! {
+    fn pow_same_as_u128(base: u128, exp: RangedU32<0, 130>) -> bool {
+        // arrange
+        let exp = exp.get();
+        let the_base = u206265::from(base);
+
+        // act
+        let the_result = the_base.checked_pow(exp);
+
+        // assert
+        match base.checked_pow(exp) {
+            Some(expected) => the_result == Some(u206265::from(expected)),
+            // might still fit into u206265
+            None => the_result.is_none() || the_result > Some(u206265::MAX_U128),
+        }
+    }
+}
+
+quickcheck! {
+    fn pow_repeated_mul(seed: u64, limbs: RangedUsize<0, 64>, exp: RangedU32<0, 64>) -> bool {
+        // arrange
+        let the_base = random_u206265(seed, limbs.get());
+        let mut expected = (u206265::ONE, false);
+        for _ in 0..exp.get() {
+            let (product, overflow) = crate::const_mul(&expected.0, &the_base);
+            expected = (product, expected.1 || overflow);
+        }
+
+        // act
+        let the_result = the_base.overflowing_pow(exp.get());
+
+        // assert
+        the_result == expected
+    }
+}
+
+#[test]
+fn pow_of_two() {
+    // arrange
+    let two = u206265::from(2u8);
+
+    // act, assert
+    assert_eq!(
+        two.checked_pow(BITS_U32 - 1),
+        Some(crate::const_shl(&u206265::ONE, BITS_U32 - 1).0)
+    );
+    assert_eq!(two.checked_pow(BITS_U32), None);
+    assert_eq!(two.overflowing_pow(BITS_U32), (u206265::ZERO, true));
+    assert_eq!(two.saturating_pow(BITS_U32), u206265::MAX);
+    assert_eq!(u206265::ZERO.wrapping_pow(0), u206265::ONE);
+    assert_eq!(u206265::MAX.overflowing_pow(1), (u206265::MAX, false));
+}
+
+quickcheck! {
+    fn checked_pow_same_as_overflowing(seed: u64, limbs: RangedUsize<0, 4>, exp: RangedU32<0, 2_000>) -> bool {
+        // arrange
+        let the_base = random_u206265(seed, limbs.get());
+        let (power, overflow) = crate::const_pow(&the_base, exp.get());
+
+        // act
+        let the_result = crate::const_checked_pow(&the_base, exp.get());
+
+        // assert
+        the_result == (!overflow).then_some(power)
+    }
+}
+
+quickcheck! {
+    fn pow_u206265_same_as_pow(seed: u64, limbs: RangedUsize<0, 16>, exp: RangedU32<0, 100>) -> bool {
+        // arrange
+        let the_base = random_u206265(seed, limbs.get());
+
+        // act, assert
+        crate::const_pow_u206265(&the_base, &u206265::from(exp.get())) == crate::const_pow(&the_base, exp.get())
+    }
+}
+
+#[test]
+fn pow_guaranteed_overflow() {
+    // arrange
+    let two = u206265::from(2u8);
+    let three = u206265::from(3u8);
+    let huge = crate::const_shl(&u206265::ONE, 1000).0;
+
+    // act, assert
+    // these return without multiplying anything
+    assert_eq!(three.checked_pow(u32::MAX), None);
+    assert_eq!(three.saturating_pow(u32::MAX), u206265::MAX);
+    assert_eq!(two.overflowing_pow(u32::MAX), (u206265::ZERO, true));
+    assert_eq!(
+        crate::const_pow_u206265(&two, &u206265::MAX),
+        (u206265::ZERO, true)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&u206265::ONE, &u206265::MAX),
+        (u206265::ONE, false)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&u206265::ZERO, &u206265::MAX),
+        (u206265::ZERO, false)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&huge, &u206265::ZERO),
+        (u206265::ONE, false)
+    );
+}
+
+#[test]
+fn pow_u206265_multi_limb_exponent() {
+    // arrange
+    let three = u206265::from(3u8);
+    // 3^(2^32) and 3^(2^64) by squaring
+    let power_32 = crate::const_sqr(&crate::const_pow(&three, 1 << 31).0).0;
+    let expected = crate::const_sqr(&crate::const_pow(&power_32, 1 << 31).0).0;
+
+    // act
+    let the_result = crate::const_pow_u206265(&three, &u206265::from(1u128 << 64));
+
+    // assert
+    assert_eq!(the_result, (expected, true));
+}
+
+#[test]
+fn pow_u206265_odd_base_wraps() {
+    // arrange
+    // 1 + 2^206240 has order 2^25, and its inverse is 1 - 2^206240
+    let base = crate::const_add(&crate::const_shl(&u206265::ONE, 206_240).0, &u206265::ONE).0;
+    let inverse = crate::const_sub(&u206265::ONE, &crate::const_shl(&u206265::ONE, 206_240).0).0;
+
+    // act, assert
+    // these take a couple of squarings at most
+    assert_eq!(
+        crate::const_pow_u206265(&u206265::MAX, &u206265::MAX),
+        (u206265::MAX, true)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&u206265::MAX, &(u206265::MAX - u206265::ONE)),
+        (u206265::ONE, true)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&base, &u206265::MAX),
+        (inverse.const_clone(), true)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&base, &u206265::from((1u128 << 25) - 1)),
+        (inverse, true)
+    );
+    assert_eq!(
+        crate::const_pow_u206265(&base, &u206265::from(1u128 << 25)),
+        (u206265::ONE, true)
+    );
+}
+
+#[test]
+fn pow_method() {
+    // act, assert
+    assert_eq!(u206265::from(10u8).pow(30), u206265::from(10u128.pow(30)));
+    assert_eq!(
+        u206265::from(7u8).pow_u206265(&u206265::from(40u8)),
+        u206265::from(7u128.pow(40))
+    );
+}
+
+#[test]
+fn division_families() {
+    // arrange
+    let the_lhs = u206265::from(100u8);
+    let the_rhs = u206265::from(7u8);
+
+    // act, assert
+    assert_eq!(the_lhs.checked_div(&u206265::ZERO), None);
+    assert_eq!(the_lhs.checked_rem(&u206265::ZERO), None);
+    assert_eq!(the_lhs.wrapping_div(&the_rhs), u206265::from(14u8));
+    assert_eq!(the_lhs.wrapping_rem(&the_rhs), u206265::from(2u8));
+    assert_eq!(
+        the_lhs.overflowing_div(&the_rhs),
+        (u206265::from(14u8), false)
+    );
+    assert_eq!(
+        the_lhs.overflowing_rem(&the_rhs),
+        (u206265::from(2u8), false)
+    );
+    assert_eq!(the_lhs.saturating_div(&the_rhs), u206265::from(14u8));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn wrapping_div_by_zero() {
+    let _ = u206265::ONE.wrapping_div(&u206265::ZERO);
+}
+
+quickcheck! {
+    fn neg_family(seed: u64, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let is_zero = the_val == u206265::ZERO;
+
+        // act
+        let (the_neg, the_overflow) = the_val.overflowing_neg();
+
+        // assert
+        crate::const_add(&the_val, &the_neg) == (u206265::ZERO, !is_zero)
+            && the_overflow != is_zero
+            && the_val.wrapping_neg() == the_neg
+            && the_val.checked_neg() == is_zero.then_some(u206265::ZERO)
+    }
+}
+
+quickcheck! {
+    fn shift_families(seed: u64, limbs: RangedUsize<0, 3223>, shift: RangedU32<0, 412_600>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let shift = shift.get();
+        let fits = shift < BITS_U32;
+        let shl = crate::const_shl(&the_val, shift % BITS_U32).0;
+        let shr = crate::const_shr(&the_val, shift % BITS_U32).0;
+
+        // act, assert
+        the_val.overflowing_shl(shift) == (shl.const_clone(), !fits)
+            && the_val.wrapping_shl(shift) == shl
+            && the_val.checked_shl(shift) == fits.then_some(shl)
+            && the_val.overflowing_shr(shift) == (shr.const_clone(), !fits)
+            && the_val.wrapping_shr(shift) == shr
+            && the_val.checked_shr(shift) == fits.then_some(shr)
+    }
+}
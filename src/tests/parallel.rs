@@ -0,0 +1,194 @@
+extern crate std;
+
+use alloc::vec::Vec;
+
+use deranged::{RangedU8, RangedUsize};
+
+use super::random_u206265;
+use crate::{pure_rust_impl::parallel, u206265};
+
+/// Number of threads to split the work between. Machine running the tests might have only one core, but the work should be split anyway.
+const THREADS: usize = 4;
+
+quickcheck! {
+    fn parallel_mul(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let expected = crate::const_mul(&the_lhs, &the_rhs);
+
+        // act
+        let the_result = parallel::mul(&the_lhs, &the_rhs, THREADS);
+
+        // assert
+        the_result == expected
+    }
+}
+
+macro_rules! special_parallel_mul {
+    ($lhs_limbs:literal, $rhs_limbs:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_parallel_mul_ $lhs_limbs _by_ $rhs_limbs>]() {
+                // arrange
+                let the_lhs = random_u206265($lhs_limbs, $lhs_limbs);
+                let the_rhs = random_u206265($rhs_limbs, $rhs_limbs);
+                let expected = crate::const_mul(&the_lhs, &the_rhs);
+
+                // act
+                let the_result = parallel::mul(&the_lhs, &the_rhs, THREADS);
+
+                // assert
+                assert_eq!(the_result, expected);
+            }
+        }
+    };
+}
+
+// Karatsuba split
+special_parallel_mul!(256, 256);
+special_parallel_mul!(1000, 900);
+special_parallel_mul!(3223, 3000);
+// halves
+special_parallel_mul!(1000, 256);
+special_parallel_mul!(3223, 1000);
+// transform
+special_parallel_mul!(2000, 2000);
+special_parallel_mul!(3223, 3223);
+
+#[test]
+fn parallel_mul_max() {
+    // arrange
+    let expected = crate::const_mul(&u206265::MAX, &u206265::MAX);
+
+    // act
+    let the_result = parallel::mul(&u206265::MAX, &u206265::MAX, THREADS);
+
+    // assert
+    assert_eq!(the_result, expected);
+}
+
+quickcheck! {
+    fn parallel_to_chunks(seed: u64, limbs: RangedUsize<0, 3223>, threads: RangedUsize<1, 9>) -> bool {
+        // arrange
+        let val = random_u206265(seed, limbs.get());
+        let mut expected = [0u128; crate::pure_rust_impl::decimal::CHUNKS];
+        crate::pure_rust_impl::decimal::to_chunks(&mut expected, val.limbs());
+
+        // act
+        let mut the_chunks = [0u128; crate::pure_rust_impl::decimal::CHUNKS];
+        parallel::to_chunks(&mut the_chunks, val.limbs(), threads.get());
+
+        // assert
+        the_chunks == expected
+    }
+}
+
+#[test]
+fn parallel_to_chunks_max() {
+    // arrange
+    let mut expected = [0u128; crate::pure_rust_impl::decimal::CHUNKS];
+    crate::pure_rust_impl::decimal::to_chunks(&mut expected, u206265::MAX.limbs());
+
+    // act
+    let mut the_chunks = [0u128; crate::pure_rust_impl::decimal::CHUNKS];
+    parallel::to_chunks(&mut the_chunks, u206265::MAX.limbs(), THREADS);
+
+    // assert
+    assert_eq!(the_chunks, expected);
+}
+
+/// Numbers to fold, with all sorts of lengths.
+fn fold_items(seed: u64, count: usize, limbs: usize) -> Vec<u206265> {
+    (0..count as u64)
+        .map(|i| {
+            random_u206265(
+                seed.wrapping_add(i),
+                (seed.wrapping_mul(i) % (limbs as u64 + 1)) as usize,
+            )
+        })
+        .collect()
+}
+
+quickcheck! {
+    fn parallel_sum(seed: u64, count: RangedUsize<0, 100>, limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let items = fold_items(seed, count.get(), limbs.get());
+        let expected = items.iter().fold(u206265::ZERO, |sum, num| crate::const_add(&sum, num).0);
+
+        // act
+        let the_sum = parallel::fold(items.into_iter(), &u206265::ZERO, |sum, num| {
+            crate::const_add_assign(sum, num);
+        }, 0, || THREADS);
+
+        // assert
+        the_sum == expected
+    }
+}
+
+quickcheck! {
+    fn parallel_product(seed: u64, count: RangedUsize<0, 100>, limbs: RangedU8<0, 64>) -> bool {
+        // arrange
+        let items = fold_items(seed, count.get(), limbs.get().into());
+        let expected = items.iter().fold(u206265::ONE, |prod, num| crate::const_mul(&prod, num).0);
+
+        // act
+        let the_product = parallel::fold(items.into_iter(), &u206265::ONE, |prod, num| {
+            crate::const_mul_assign(prod, num);
+        }, 0, || THREADS);
+
+        // assert
+        the_product == expected
+    }
+}
+
+#[test]
+fn parallel_fold_passes_panic() {
+    // arrange
+    let items = fold_items(1, 100, 10);
+
+    // act
+    let the_result = std::panic::catch_unwind(|| {
+        parallel::fold(
+            items.into_iter(),
+            &u206265::ZERO,
+            |_, num| assert!(num.significant_limbs() < 5, "that's too long"),
+            0,
+            || THREADS,
+        )
+    });
+
+    // assert
+    assert!(the_result.is_err());
+}
+
+quickcheck! {
+    fn parallel_fold_small_items(seed: u64, count: RangedUsize<0, 100>, limbs: RangedU8<0, 8>) -> bool {
+        // arrange
+        let items = fold_items(seed, count.get(), limbs.get().into());
+        let expected = items.iter().fold(u206265::ZERO, |sum, num| crate::const_add(&sum, num).0);
+
+        // act
+        // short items are folded right away, without even finding the number of threads
+        let the_sum = parallel::fold(items.into_iter(), &u206265::ZERO, |sum, num| {
+            crate::const_add_assign(sum, num);
+        }, parallel::FOLD_LIMBS_THRESHOLD, || unreachable!("threads should not be needed"));
+
+        // assert
+        the_sum == expected
+    }
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "u206265 mul overflow"))]
+fn parallel_product_overflow() {
+    // arrange
+    // each item is 94 limbs long, so the product is worth splitting, and its 2^240_000 doesn't fit
+    let items = (0..40).map(|_| crate::const_shl(&u206265::ONE, 6_000).0);
+
+    // act
+    let the_product: u206265 = items.product();
+
+    // assert
+    assert_eq!(the_product, u206265::ZERO);
+}
@@ -0,0 +1,169 @@
+use alloc::{format, string::String};
+use core::num::IntErrorKind;
+
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::{u206265, u206265FromStr};
+
+quickcheck! {
+    fn same_as_u128(val: u128, radix: RangedU32<2, 36>) -> bool {
+        // arrange
+        let radix = radix.get();
+        let src = format_radix(val, radix);
+
+        // act, assert
+        u206265::from_str_radix(&src, radix) == Ok(u206265::from(val))
+            && u206265::from_str_radix(&src.to_uppercase(), radix) == Ok(u206265::from(val))
+            && format!("{val}").parse::<u206265>() == Ok(u206265::from(val))
+    }
+}
+
+/// Digits of `val` in the given radix, most significant first.
+fn format_radix(mut val: u128, radix: u32) -> String {
+    let mut digits = String::new();
+    loop {
+        let digit = u32::try_from(val % u128::from(radix)).unwrap();
+        digits.insert(0, char::from_digit(digit, radix).unwrap());
+        val /= u128::from(radix);
+        if val == 0 {
+            break;
+        }
+    }
+    digits
+}
+
+quickcheck! {
+    fn round_trip(seed: u64, limbs: RangedUsize<0, 300>) -> bool {
+        // arrange
+        let val = random_u206265(seed, limbs.get());
+
+        // act, assert
+        format!("{val}").parse::<u206265>() == Ok(val.const_clone())
+            && u206265::from_str_radix(&format!("{val:x}"), 16) == Ok(val.const_clone())
+            && u206265::from_str_radix(&format!("{val:X}"), 16) == Ok(val.const_clone())
+            && u206265::from_str_radix(&format!("{val:o}"), 8) == Ok(val.const_clone())
+            && u206265::from_str_radix(&format!("{val:b}"), 2) == Ok(val)
+    }
+}
+
+quickcheck! {
+    fn invalid_digit(val: u128, radix: RangedU32<2, 36>, position: usize) -> bool {
+        // arrange
+        let radix = radix.get();
+        let mut src = format_radix(val, radix);
+        let position = position % (src.len() + 1);
+        // first digit, not valid in the radix, if there's one
+        let invalid = char::from_digit(radix, 36).unwrap_or('_');
+        src.insert(position, invalid);
+
+        // act, assert
+        u206265::from_str_radix(&src, radix) == Err(u206265FromStr::InvalidDigit { position })
+    }
+}
+
+#[test]
+fn parse_max() {
+    // arrange
+    let max = format!("{}", u206265::MAX);
+    let mut above_max = format!("{:b}", u206265::MAX);
+    above_max.push('0');
+
+    // act, assert
+    assert_eq!(max.parse::<u206265>(), Ok(u206265::MAX));
+    assert_eq!(
+        format!("{max}0").parse::<u206265>(),
+        Err(u206265FromStr::Overflow)
+    );
+    assert_eq!(
+        u206265::from_str_radix(&above_max, 2),
+        Err(u206265FromStr::Overflow)
+    );
+    assert_eq!(
+        u206265::from_str_radix(&format!("1{}", "0".repeat(206_265)), 2),
+        Err(u206265FromStr::Overflow)
+    );
+    assert_eq!(
+        u206265::from_str_radix(&format!("1{}", "0".repeat(206_264)), 2),
+        Ok(crate::const_shl(&u206265::ONE, 206_264).0)
+    );
+}
+
+#[test]
+fn parse_overflow_before_invalid_digit() {
+    // arrange
+    let max = format!("{}", u206265::MAX);
+    let max_bin = format!("{:b}", u206265::MAX);
+
+    // act, assert
+    // std reports the overflow, if it happens before the invalid digit
+    assert_eq!(
+        format!("{}0x", u128::MAX)
+            .parse::<u128>()
+            .unwrap_err()
+            .kind(),
+        &IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        format!("{max}0x").parse::<u206265>(),
+        Err(u206265FromStr::Overflow)
+    );
+    assert_eq!(
+        format!("{max}x").parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit {
+            position: max.len()
+        })
+    );
+    // overflowing digit at every offset within a chunk
+    for zeros in 1..=70 {
+        assert_eq!(
+            u206265::from_str_radix(&format!("{max_bin}{}2", "0".repeat(zeros)), 2),
+            Err(u206265FromStr::Overflow)
+        );
+    }
+    assert_eq!(
+        u206265::from_str_radix(&format!("{max_bin}2"), 2),
+        Err(u206265FromStr::InvalidDigit {
+            position: max_bin.len()
+        })
+    );
+}
+
+#[test]
+fn parse_edge_cases() {
+    // act, assert
+    assert_eq!("".parse::<u206265>(), Err(u206265FromStr::Empty));
+    assert_eq!(
+        "+".parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit { position: 0 })
+    );
+    assert_eq!(
+        "-1".parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit { position: 0 })
+    );
+    assert_eq!(
+        "1+".parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit { position: 1 })
+    );
+    assert_eq!(
+        " 1".parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit { position: 0 })
+    );
+    assert_eq!(
+        "1é".parse::<u206265>(),
+        Err(u206265FromStr::InvalidDigit { position: 1 })
+    );
+    assert_eq!("+42".parse::<u206265>(), Ok(u206265::from(42u8)));
+    assert_eq!("0".repeat(100_000).parse::<u206265>(), Ok(u206265::ZERO));
+    assert_eq!(
+        u206265::from_str_radix("zZ", 36),
+        Ok(u206265::from(1295u16))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Radix should be in range 2..=36")]
+fn parse_bad_radix() {
+    // act, assert
+    let _ = u206265::from_str_radix("0", 37);
+}
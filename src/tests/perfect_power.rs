@@ -0,0 +1,100 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::u206265;
+
+/// Finds the smallest base and the largest exponent the slow way, trying every exponent.
+fn naive_perfect_power(val: u128) -> Option<(u128, u32)> {
+    (2..u128::BITS).rev().find_map(|exp| {
+        let mut root = 0u128;
+        for bit in (0..u128::BITS).rev() {
+            let candidate = root | (1 << bit);
+            if candidate.checked_pow(exp).is_some_and(|power| power <= val) {
+                root = candidate;
+            }
+        }
+        (root > 1 && root.pow(exp) == val).then_some((root, exp))
+    })
+}
+
+quickcheck! {
+    fn perfect_power_same_as_naive(val: u16, shift: RangedU32<0, 112>) -> bool {
+        // arrange
+        // small values are way more likely to be powers
+        let val = u128::from(val) << shift.get();
+        let expected = naive_perfect_power(val);
+
+        let the_val = u206265::from(val);
+
+        // act
+        let the_result = the_val.perfect_power();
+
+        // assert
+        the_result == expected.map(|(base, exp)| (u206265::from(base), exp))
+            && the_val.is_perfect_square() == (val.isqrt().pow(2) == val)
+    }
+}
+
+quickcheck! {
+    fn is_perfect_square_same_as_isqrt(val: u128) -> bool {
+        // act, assert
+        u206265::from(val).is_perfect_square() == (val.isqrt().pow(2) == val)
+    }
+}
+
+quickcheck! {
+    fn perfect_power_of_random_base(seed: u64, limbs: RangedUsize<1, 4>, exp: RangedU32<2, 100>) -> bool {
+        // arrange
+        // a random base is never a power itself
+        let base = random_u206265(seed, limbs.get());
+        let exp = exp.get();
+        let (power, _) = crate::const_pow(&base, exp);
+        let (below, _) = crate::const_sub(&power, &u206265::ONE);
+
+        // act, assert
+        power.perfect_power() == Some((base, exp))
+            && power.is_perfect_square() == exp.is_multiple_of(2)
+            && below.perfect_power().is_none()
+    }
+}
+
+quickcheck! {
+    fn perfect_power_of_square(seed: u64, exp: RangedU32<2, 100>) -> bool {
+        // arrange
+        let base = random_u206265(seed, 1);
+        let (square, _) = crate::const_sqr(&base);
+        let (power, _) = crate::const_pow(&square, exp.get());
+
+        // act, assert
+        power.perfect_power() == Some((base, 2 * exp.get()))
+    }
+}
+
+#[test]
+fn perfect_power_trivial() {
+    // act, assert
+    assert_eq!(u206265::ZERO.perfect_power(), None);
+    assert_eq!(u206265::ONE.perfect_power(), None);
+    assert!(u206265::ZERO.is_perfect_square());
+    assert!(u206265::ONE.is_perfect_square());
+    // 2^206265 - 1 is never a power
+    assert_eq!(u206265::MAX.perfect_power(), None);
+    assert!(!u206265::MAX.is_perfect_square());
+}
+
+#[test]
+fn perfect_power_large_exponents() {
+    // arrange
+    let two = u206265::from(2u8);
+    let six = u206265::from(6u8);
+
+    // act, assert
+    assert_eq!(
+        crate::const_shl(&u206265::ONE, 206_264).0.perfect_power(),
+        Some((two, 206_264))
+    );
+    assert_eq!(
+        crate::const_pow(&six, 79_000).0.perfect_power(),
+        Some((six, 79_000))
+    );
+}
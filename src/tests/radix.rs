@@ -0,0 +1,87 @@
+use alloc::{format, vec};
+
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::u206265;
+
+quickcheck! {
+    fn same_as_format(val: u128) -> bool {
+        // arrange
+        let the_val = u206265::from(val);
+        let mut buf = [0u8; 128];
+
+        // act, assert
+        the_val.to_str_radix(2, &mut buf) == Some(format!("{val:b}").as_str())
+            && the_val.to_str_radix(8, &mut buf) == Some(format!("{val:o}").as_str())
+            && the_val.to_str_radix(10, &mut buf) == Some(format!("{val}").as_str())
+            && the_val.to_str_radix(16, &mut buf) == Some(format!("{val:x}").as_str())
+    }
+}
+
+quickcheck! {
+    fn round_trip(val: u128, radix: RangedU32<2, 36>) -> bool {
+        // arrange
+        let radix = radix.get();
+        let the_val = u206265::from(val);
+        let len = the_val.formatted_len(radix);
+        let mut buf = [0u8; 128];
+
+        // act
+        let written = the_val.write_str_radix(radix, &mut buf[..len]);
+        let parsed = u206265::from_str_radix(core::str::from_utf8(&buf[..len]).unwrap(), radix);
+
+        // assert
+        written == Some(len)
+            && parsed == Ok(the_val.const_clone())
+            && the_val.write_str_radix(radix, &mut buf[..len - 1]).is_none()
+    }
+}
+
+quickcheck! {
+    fn round_trip_large(seed: u64, limbs: RangedUsize<0, 300>, radix: RangedU32<2, 36>) -> bool {
+        // arrange
+        let radix = radix.get();
+        let the_val = random_u206265(seed, limbs.get());
+        let len = the_val.formatted_len(radix);
+        let mut buf = vec![0u8; len];
+
+        // act
+        let digits = the_val.to_str_radix(radix, &mut buf);
+
+        // assert
+        digits.map(str::len) == Some(len)
+            && digits.and_then(|digits| u206265::from_str_radix(digits, radix).ok()) == Some(the_val)
+    }
+}
+
+#[test]
+fn radix_of_max() {
+    // arrange
+    let mut buf = vec![0u8; 206_265];
+
+    // act, assert
+    for radix in [2, 10, 16, 36] {
+        let len = u206265::MAX.formatted_len(radix);
+        let digits = u206265::MAX.to_str_radix(radix, &mut buf).unwrap();
+        assert_eq!(digits.len(), len);
+        assert_eq!(u206265::from_str_radix(digits, radix), Ok(u206265::MAX));
+    }
+    assert_eq!(
+        u206265::MAX.to_str_radix(10, &mut buf),
+        Some(format!("{}", u206265::MAX).as_str())
+    );
+}
+
+#[test]
+fn radix_of_zero() {
+    // arrange
+    let mut buf = [0u8; 1];
+
+    // act, assert
+    for radix in 2..=36 {
+        assert_eq!(u206265::ZERO.formatted_len(radix), 1);
+        assert_eq!(u206265::ZERO.to_str_radix(radix, &mut buf), Some("0"));
+        assert_eq!(u206265::ZERO.write_str_radix(radix, &mut []), None);
+    }
+}
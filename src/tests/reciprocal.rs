@@ -0,0 +1,148 @@
+use deranged::RangedUsize;
+
+use super::random_u206265;
+use crate::{
+    LIMBS, Reciprocal,
+    pure_rust_impl::{barrett, div},
+    u206265,
+};
+
+/// Reciprocal, computed with a long division.
+fn inverse_reference(divisor: &u206265) -> [u64; barrett::RECIPROCAL_LEN] {
+    let mut numerator = [0u64; barrett::INVERSE_LEN + 1];
+    numerator[barrett::INVERSE_LEN] = 1;
+    let mut quotient = [0u64; barrett::INVERSE_LEN + 1];
+    let mut remainder = [0u64; LIMBS];
+    let mut scratch = [0u64; 2 * LIMBS + 4];
+    div::div_rem(
+        &mut quotient,
+        &mut remainder,
+        &numerator,
+        divisor.limbs(),
+        &mut scratch,
+    );
+    quotient[..barrett::RECIPROCAL_LEN].try_into().unwrap()
+}
+
+fn barrett_div_rem(lhs: &u206265, divisor: &u206265) -> (u206265, u206265) {
+    let mut inverse = [0u64; barrett::RECIPROCAL_LEN];
+    let mut scratch = [0u64; barrett::INVERSE_SCRATCH_LEN];
+    barrett::inverse(&mut inverse, divisor.limbs(), &mut scratch);
+    let mut quotient = [0u64; LIMBS];
+    let mut remainder = [0u64; LIMBS];
+    barrett::div_rem(
+        &mut quotient,
+        &mut remainder,
+        lhs.limbs(),
+        divisor.limbs(),
+        &inverse,
+        &mut scratch,
+    );
+    (
+        u206265::from_limbs(quotient),
+        u206265::from_limbs(remainder),
+    )
+}
+
+quickcheck! {
+    fn inverse(seed: u64, limbs: RangedUsize<2, 3223>) -> bool {
+        // arrange
+        let the_divisor = random_u206265(seed, limbs.get());
+        let expected = inverse_reference(&the_divisor);
+        let mut the_inverse = [0u64; barrett::RECIPROCAL_LEN];
+        let mut scratch = [0u64; barrett::INVERSE_SCRATCH_LEN];
+
+        // act
+        barrett::inverse(&mut the_inverse, the_divisor.limbs(), &mut scratch);
+
+        // assert
+        the_inverse == expected
+    }
+}
+
+quickcheck! {
+    fn barrett(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<2, 300>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let expected = crate::const_div_rem(&the_lhs, &the_rhs).unwrap();
+
+        // act
+        let the_result = barrett_div_rem(&the_lhs, &the_rhs);
+
+        // assert
+        the_result == expected
+    }
+}
+
+quickcheck! {
+    fn reciprocal_div_rem(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<1, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let expected = crate::const_div_rem(&the_lhs, &the_rhs).unwrap();
+        let the_reciprocal = Reciprocal::new(&the_rhs).unwrap();
+
+        // act
+        let the_result = the_reciprocal.div_rem(&the_lhs);
+
+        // assert
+        the_result == expected
+            && the_reciprocal.div(&the_lhs) == expected.0
+            && the_reciprocal.rem(&the_lhs) == expected.1
+    }
+}
+
+macro_rules! special_barrett {
+    ($lhs_limbs:literal, $rhs_limbs:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_barrett_ $lhs_limbs _by_ $rhs_limbs>]() {
+                // arrange
+                let the_lhs = random_u206265($lhs_limbs, $lhs_limbs);
+                let the_rhs = random_u206265($rhs_limbs, $rhs_limbs);
+                let expected = crate::const_div_rem(&the_lhs, &the_rhs).unwrap();
+
+                // act
+                let the_result = barrett_div_rem(&the_lhs, &the_rhs);
+
+                // assert
+                assert_eq!(the_result, expected);
+            }
+        }
+    };
+}
+
+special_barrett!(3223, 2);
+special_barrett!(3223, 1612);
+special_barrett!(3223, 1613);
+special_barrett!(3223, 3223);
+special_barrett!(3222, 3223);
+
+#[test]
+fn barrett_max() {
+    // arrange
+    let divisors = [
+        u206265::MAX,
+        crate::const_shr(&u206265::MAX, 64).0,
+        crate::const_shl(&u206265::ONE, 206_000).0,
+        crate::const_shl(&u206265::ONE, 64).0,
+        crate::const_add(&crate::const_shl(&u206265::ONE, 64).0, &u206265::ONE).0,
+    ];
+
+    for the_divisor in divisors {
+        // act
+        let the_result = barrett_div_rem(&u206265::MAX, &the_divisor);
+
+        // assert
+        assert_eq!(
+            the_result,
+            crate::const_div_rem(&u206265::MAX, &the_divisor).unwrap()
+        );
+    }
+}
+
+#[test]
+fn reciprocal_of_zero() {
+    assert_eq!(Reciprocal::new(&u206265::ZERO), None);
+}
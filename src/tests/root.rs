@@ -0,0 +1,124 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::u206265;
+
+/// Checks that ``root`` is the ``n``-th root of ``val`` rounded down, and ``remainder`` is what's left.
+fn is_iroot_rem(val: &u206265, n: u32, root: &u206265, remainder: &u206265) -> bool {
+    let Some(power) = crate::const_checked_pow(root, n) else {
+        return false;
+    };
+    let next_power = crate::const_checked_pow(&crate::const_add(root, &u206265::ONE).0, n);
+    crate::const_add(&power, remainder).0 == *val
+        && next_power.is_none_or(|next_power| next_power > *val)
+}
+
+quickcheck! {
+    fn iroot_same_as_u128(val: u128, n: RangedU32<1, 130>) -> bool {
+        // arrange
+        let n = n.get();
+        // bit by bit
+        let mut root = 0u128;
+        for bit in (0..u128::BITS).rev() {
+            let candidate = root | (1 << bit);
+            if candidate.checked_pow(n).is_some_and(|power| power <= val) {
+                root = candidate;
+            }
+        }
+
+        let the_val = u206265::from(val);
+
+        // act
+        let the_result = crate::const_iroot_rem(&the_val, n);
+
+        // assert
+        the_result == Some((u206265::from(root), u206265::from(val - root.pow(n))))
+    }
+}
+
+quickcheck! {
+    fn iroot_rem(seed: u64, limbs: RangedUsize<0, 32>, n: RangedU32<1, 3000>) -> bool {
+        // arrange
+        let n = n.get();
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let (the_root, the_remainder) = the_val.iroot_rem(n);
+
+        // assert
+        is_iroot_rem(&the_val, n, &the_root, &the_remainder)
+            && the_val.iroot(n) == the_root
+            && the_val.is_perfect_kth_power(n) == (the_remainder == u206265::ZERO)
+    }
+}
+
+quickcheck! {
+    fn iroot_of_power(seed: u64, limbs: RangedUsize<1, 3>, n: RangedU32<2, 10>) -> bool {
+        // arrange
+        let n = n.get();
+        let root = random_u206265(seed, limbs.get());
+        let (power, _) = crate::const_pow(&root, n);
+        let (below, _) = crate::const_sub(&power, &u206265::ONE);
+
+        // act, assert
+        power.iroot_rem(n) == (root.const_clone(), u206265::ZERO)
+            && below.iroot(n) == crate::const_sub(&root, &u206265::ONE).0
+    }
+}
+
+quickcheck! {
+    fn iroot_2_same_as_isqrt(seed: u64, limbs: RangedUsize<0, 32>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act, assert
+        crate::const_iroot_rem(&the_val, 2) == Some(crate::const_sqrt_rem(&the_val))
+    }
+}
+
+macro_rules! special_iroot_rem {
+    ($limbs:literal, $n:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_iroot_rem_ $limbs _ $n>]() {
+                // arrange
+                let the_val = random_u206265($limbs, $limbs);
+
+                // act
+                let (the_root, the_remainder) = the_val.iroot_rem($n);
+
+                // assert
+                assert!(is_iroot_rem(&the_val, $n, &the_root, &the_remainder));
+            }
+        }
+    };
+}
+
+// Newton's iteration
+special_iroot_rem!(3223, 3);
+special_iroot_rem!(3223, 1000);
+special_iroot_rem!(1000, 15);
+// bit by bit
+special_iroot_rem!(3223, 3500);
+special_iroot_rem!(3223, 206_264);
+special_iroot_rem!(3223, 206_265);
+
+#[test]
+fn iroot_rem_trivial() {
+    // act, assert
+    assert_eq!(crate::const_iroot_rem(&u206265::MAX, 0), None);
+    assert_eq!(u206265::MAX.iroot_rem(1), (u206265::MAX, u206265::ZERO));
+    assert_eq!(u206265::ZERO.iroot_rem(7), (u206265::ZERO, u206265::ZERO));
+    assert_eq!(
+        u206265::ONE.iroot_rem(u32::MAX),
+        (u206265::ONE, u206265::ZERO)
+    );
+    assert_eq!(u206265::MAX.iroot(u32::MAX), u206265::ONE);
+    assert_eq!(u206265::MAX.iroot(206_264), u206265::from(2u8));
+}
+
+#[test]
+#[should_panic(expected = "Zeroth root")]
+fn iroot_zeroth() {
+    let _ = u206265::ONE.iroot(0);
+}
@@ -0,0 +1,164 @@
+use core::cmp::Ordering;
+
+use deranged::RangedUsize;
+
+use super::random_u206265;
+use crate::{Rounding, u206265};
+
+/// Rounds ``lhs / rhs`` half to even the slow way.
+fn half_even(lhs: u128, rhs: u128) -> u128 {
+    let (quotient, remainder) = (lhs / rhs, lhs % rhs);
+    match remainder.cmp(&(rhs - remainder)) {
+        core::cmp::Ordering::Less => quotient,
+        core::cmp::Ordering::Equal => quotient + (quotient & 1),
+        core::cmp::Ordering::Greater => quotient + 1,
+    }
+}
+
+quickcheck! {
+    fn same_as_u128(lhs: u128, rhs: u128) -> bool {
+        // arrange
+        let the_lhs = u206265::from(lhs);
+        let the_rhs = u206265::from(rhs);
+
+        // act, assert
+        the_lhs.abs_diff(&the_rhs) == u206265::from(lhs.abs_diff(rhs))
+            && the_lhs.midpoint(&the_rhs) == u206265::from(lhs.midpoint(rhs))
+            && (rhs == 0 || the_lhs.div_ceil(&the_rhs) == u206265::from(lhs.div_ceil(rhs)))
+            && (rhs == 0 || the_lhs.div_round(&the_rhs, Rounding::Floor) == u206265::from(lhs / rhs))
+            && (rhs == 0 || the_lhs.div_round(&the_rhs, Rounding::HalfEven) == u206265::from(half_even(lhs, rhs)))
+    }
+}
+
+quickcheck! {
+    fn next_multiple_of_same_as_u128(lhs: u128, rhs: u128) -> bool {
+        // arrange
+        // u128 overflows way before u206265 does
+        let lhs = lhs >> 1;
+        let rhs = rhs >> 1;
+        let the_lhs = u206265::from(lhs);
+        let the_rhs = u206265::from(rhs);
+
+        // act, assert
+        the_lhs.checked_next_multiple_of(&the_rhs) == lhs.checked_next_multiple_of(rhs).map(u206265::from)
+            && (rhs == 0 || the_lhs.next_multiple_of(&the_rhs) == u206265::from(lhs.next_multiple_of(rhs)))
+    }
+}
+
+quickcheck! {
+    fn half_even_small(lhs: u8, rhs: u8) -> bool {
+        // arrange
+        // halves are way more likely with small numbers
+        let the_lhs = u206265::from(lhs);
+        let the_rhs = u206265::from(rhs);
+
+        // act, assert
+        rhs == 0 || the_lhs.div_round(&the_rhs, Rounding::HalfEven) == u206265::from(half_even(lhs.into(), rhs.into()))
+    }
+}
+
+quickcheck! {
+    fn div_round_large(seed: u64, lhs_limbs: RangedUsize<0, 1000>, rhs_limbs: RangedUsize<1, 1000>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let (quotient, remainder) = crate::const_div_rem(&the_lhs, &the_rhs).unwrap();
+        let exact = remainder == u206265::ZERO;
+        let next = crate::const_add(&quotient, &u206265::ONE).0;
+
+        // act
+        let the_floor = the_lhs.div_round(&the_rhs, Rounding::Floor);
+        let the_ceil = the_lhs.div_ceil(&the_rhs);
+        let the_half_even = the_lhs.div_round(&the_rhs, Rounding::HalfEven);
+        let the_multiple = the_lhs.checked_next_multiple_of(&the_rhs);
+
+        // assert
+        // divisors can be even, so exact halves go to the even quotient
+        let doubled = crate::const_shl(&remainder, 1).0;
+        let round_up = match doubled.cmp(&the_rhs) {
+            Ordering::Less => false,
+            Ordering::Equal => quotient.trailing_zeros() == 0,
+            Ordering::Greater => true,
+        };
+        the_floor == quotient
+            && the_ceil == if exact { quotient.const_clone() } else { next.const_clone() }
+            && the_half_even == if round_up { next } else { quotient.const_clone() }
+            && the_multiple.is_none_or(|multiple| crate::const_rem(&multiple, &the_rhs) == Some(u206265::ZERO) && multiple >= the_lhs && crate::const_sub(&multiple, &the_lhs).0 < the_rhs)
+    }
+}
+
+quickcheck! {
+    fn div_round_large_halves(seed: u64, rhs_limbs: RangedUsize<1, 1000>, quotient_limbs: RangedUsize<0, 1000>, odd: bool) -> bool {
+        // arrange
+        // even divisor, and the remainder being exactly half of it
+        let the_rhs = crate::const_shl(&random_u206265(seed, rhs_limbs.get()), 1).0;
+        let mut quotient = random_u206265(!seed, quotient_limbs.get());
+        quotient.0[0] = quotient.0[0] & !1 | u64::from(odd);
+        let quotient = u206265::from_limbs(quotient.0);
+        let half = crate::const_shr(&the_rhs, 1).0;
+        let (the_lhs, overflow) = crate::const_mul(&the_rhs, &quotient);
+        if overflow {
+            return true;
+        }
+        let (the_lhs, overflow) = crate::const_add(&the_lhs, &half);
+        if overflow {
+            return true;
+        }
+
+        // act
+        let the_half_even = the_lhs.div_round(&the_rhs, Rounding::HalfEven);
+
+        // assert
+        the_half_even == if odd { crate::const_add(&quotient, &u206265::ONE).0 } else { quotient }
+    }
+}
+
+quickcheck! {
+    fn abs_diff_and_midpoint_large(seed: u64, lhs_limbs: RangedUsize<0, 3223>, rhs_limbs: RangedUsize<0, 3223>) -> bool {
+        // arrange
+        let the_lhs = random_u206265(seed, lhs_limbs.get());
+        let the_rhs = random_u206265(!seed, rhs_limbs.get());
+        let (min, max) = if the_lhs <= the_rhs { (&the_lhs, &the_rhs) } else { (&the_rhs, &the_lhs) };
+
+        // act
+        let the_diff = the_lhs.abs_diff(&the_rhs);
+        let the_midpoint = the_lhs.midpoint(&the_rhs);
+
+        // assert
+        crate::const_add(min, &the_diff) == (max.const_clone(), false)
+            && the_rhs.abs_diff(&the_lhs) == the_diff
+            && crate::const_add(min, &crate::const_shr(&the_diff, 1).0).0 == the_midpoint
+    }
+}
+
+#[test]
+fn edges() {
+    // arrange
+    let two = u206265::from(2u8);
+    let max_minus_one = crate::const_sub(&u206265::MAX, &u206265::ONE).0;
+
+    // act, assert
+    assert_eq!(u206265::MAX.midpoint(&u206265::MAX), u206265::MAX);
+    assert_eq!(u206265::MAX.midpoint(&max_minus_one), max_minus_one);
+    assert_eq!(u206265::MAX.abs_diff(&u206265::ZERO), u206265::MAX);
+    assert_eq!(u206265::MAX.checked_next_multiple_of(&two), None);
+    assert_eq!(u206265::MAX.checked_next_multiple_of(&u206265::ZERO), None);
+    assert_eq!(
+        max_minus_one.checked_next_multiple_of(&two),
+        Some(max_minus_one.const_clone())
+    );
+    assert_eq!(
+        u206265::MAX.div_ceil(&two),
+        crate::const_shl(&u206265::ONE, 206_264).0
+    );
+    assert_eq!(
+        u206265::MAX.div_round(&two, Rounding::HalfEven),
+        crate::const_shl(&u206265::ONE, 206_264).0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn div_ceil_by_zero() {
+    let _ = u206265::ONE.div_ceil(&u206265::ZERO);
+}
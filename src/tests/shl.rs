@@ -55,3 +55,16 @@ macro_rules! special_shl {
 }
 
 special_shl!(2_305_843_009_213_693_952, 67);
+
+#[test]
+fn shl_drops_upper_most_bit() {
+    // arrange
+    let expected = crate::const_sub(&u206265::MAX, &u206265::ONE).0;
+
+    // act
+    let (the_shift, the_ov) = crate::const_shl(&u206265::MAX, 1);
+
+    // assert
+    assert!(!the_ov, "Shifting by 1 bit is not an overflow");
+    assert_eq!(the_shift, expected);
+}
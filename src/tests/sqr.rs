@@ -0,0 +1,135 @@
+use alloc::vec;
+
+use deranged::RangedUsize;
+
+use super::random_u206265;
+use crate::{LIMBS, u206265};
+
+quickcheck! {
+    fn sqr(val: u128) -> bool {
+        // arrange
+        let the_val = u206265::from(val);
+        let expected = crate::const_mul(&the_val, &the_val);
+
+        // act
+        let the_sqr = crate::const_sqr(&the_val);
+
+        // assert
+        the_sqr == expected
+    }
+}
+
+quickcheck! {
+    fn sqr_assign(val: u128) -> bool {
+        // arrange
+        let mut the_val = u206265::from(val);
+        let expected = crate::const_mul(&the_val, &the_val);
+
+        // act
+        let the_ov = crate::const_sqr_assign(&mut the_val);
+
+        // assert
+        (the_val, the_ov) == expected
+    }
+}
+
+quickcheck! {
+    fn sqr_large(seed: u64, limbs: RangedUsize<1, 600>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let expected = crate::const_mul(&the_val, &the_val);
+
+        // act
+        let the_sqr = crate::const_sqr(&the_val);
+
+        // assert
+        the_sqr == expected
+    }
+}
+
+macro_rules! special_sqr_large {
+    ($limbs:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_sqr_large_ $limbs>]() {
+                // arrange
+                let the_val = random_u206265($limbs, $limbs);
+                let expected = crate::const_mul(&the_val, &the_val);
+
+                // act
+                let the_sqr = crate::const_sqr(&the_val);
+
+                // assert
+                assert_eq!(the_sqr, expected);
+            }
+        }
+    };
+}
+
+special_sqr_large!(47);
+special_sqr_large!(48);
+special_sqr_large!(128);
+special_sqr_large!(1000);
+special_sqr_large!(1611);
+special_sqr_large!(1612);
+special_sqr_large!(1900);
+special_sqr_large!(3223);
+
+#[test]
+fn sqr_max() {
+    // act
+    let (the_sqr, the_ov) = crate::const_sqr(&u206265::MAX);
+
+    // assert
+    assert!(the_ov, "MAX^2 should overflow");
+    assert_eq!(the_sqr, u206265::ONE);
+}
+
+#[test]
+fn square() {
+    // arrange
+    let the_val = u206265::from(u64::MAX);
+
+    // act
+    let the_sqr = the_val.square();
+
+    // assert
+    assert_eq!(
+        the_sqr,
+        u206265::from(u128::from(u64::MAX) * u128::from(u64::MAX))
+    );
+}
+
+quickcheck! {
+    fn schoolbook_sqr_truncated(seed: u64, limbs: RangedUsize<1, 100>, out_len: RangedUsize<1, 200>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let mut expected = [0u64; LIMBS];
+        crate::pure_rust_impl::mul::schoolbook(&mut expected[..out_len.get()], the_val.limbs(), the_val.limbs());
+        let mut the_sqr = [0u64; LIMBS];
+
+        // act
+        crate::pure_rust_impl::mul::schoolbook_sqr(&mut the_sqr[..out_len.get()], the_val.limbs());
+
+        // assert
+        the_sqr == expected
+    }
+}
+
+quickcheck! {
+    fn ntt_sqr(seed: u64, limbs: RangedUsize<1, 600>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+        let product_len = 2 * limbs.get();
+        let mut expected = [0u64; 2 * LIMBS];
+        crate::pure_rust_impl::mul::schoolbook(&mut expected[..product_len], the_val.limbs(), the_val.limbs());
+        let mut the_sqr = [0u64; 2 * LIMBS];
+        let mut scratch = vec![0u64; crate::pure_rust_impl::ntt::scratch_len(limbs.get(), limbs.get())];
+
+        // act
+        crate::pure_rust_impl::ntt::sqr(&mut the_sqr[..product_len], the_val.limbs(), &mut scratch);
+
+        // assert
+        the_sqr == expected
+    }
+}
@@ -0,0 +1,123 @@
+use deranged::{RangedU32, RangedUsize};
+
+use super::random_u206265;
+use crate::u206265;
+
+quickcheck! {
+    fn isqrt_same_as_u128(val: u128) -> bool {
+        // arrange
+        let root = val.isqrt();
+
+        let the_val = u206265::from(val);
+
+        // act
+        let (the_root, the_remainder) = crate::const_sqrt_rem(&the_val);
+
+        // assert
+        the_root == u206265::from(root) && the_remainder == u206265::from(val - root * root)
+    }
+}
+
+/// Checks that ``root`` is the square root of ``val`` rounded down, and ``remainder`` is what's left.
+fn is_sqrt_rem(val: &u206265, root: &u206265, remainder: &u206265) -> bool {
+    let (square, overflow) = crate::const_sqr(root);
+    let (next_square, next_overflow) = crate::const_sqr(&crate::const_add(root, &u206265::ONE).0);
+    !overflow
+        && crate::const_add(&square, remainder).0 == *val
+        && (next_overflow || next_square > *val)
+}
+
+quickcheck! {
+    fn sqrt_rem(seed: u64, limbs: RangedUsize<0, 64>) -> bool {
+        // arrange
+        let the_val = random_u206265(seed, limbs.get());
+
+        // act
+        let (the_root, the_remainder) = the_val.sqrt_rem();
+
+        // assert
+        is_sqrt_rem(&the_val, &the_root, &the_remainder)
+            && the_val.isqrt() == the_root
+            && the_val.checked_isqrt() == Some(the_root)
+    }
+}
+
+quickcheck! {
+    fn sqrt_rem_of_all_ones(bits: RangedU32<1, 8192>) -> bool {
+        // arrange
+        // 2^bits - 1 is just below a square, or twice a square, so upper bits of the root are all ones
+        let the_val = crate::const_sub(&crate::const_shl(&u206265::ONE, bits.get()).0, &u206265::ONE).0;
+
+        // act
+        let (the_root, the_remainder) = crate::const_sqrt_rem(&the_val);
+
+        // assert
+        is_sqrt_rem(&the_val, &the_root, &the_remainder)
+    }
+}
+
+quickcheck! {
+    fn isqrt_of_square(seed: u64, limbs: RangedUsize<1, 32>) -> bool {
+        // arrange
+        let root = random_u206265(seed, limbs.get());
+        let (square, _) = crate::const_sqr(&root);
+        let (below, _) = crate::const_sub(&square, &u206265::ONE);
+
+        // act
+        let (the_root, the_remainder) = crate::const_sqrt_rem(&square);
+        let the_below = crate::const_isqrt(&below);
+
+        // assert
+        the_root == root
+            && the_remainder == u206265::ZERO
+            && the_below == crate::const_sub(&root, &u206265::ONE).0
+    }
+}
+
+macro_rules! special_sqrt_rem {
+    ($limbs:literal) => {
+        ::paste::paste! {
+            #[test]
+            fn [<special_sqrt_rem_ $limbs>]() {
+                // arrange
+                let the_val = random_u206265($limbs, $limbs);
+
+                // act
+                let (the_root, the_remainder) = crate::const_sqrt_rem(&the_val);
+
+                // assert
+                assert!(is_sqrt_rem(&the_val, &the_root, &the_remainder));
+            }
+        }
+    };
+}
+
+special_sqrt_rem!(500);
+special_sqrt_rem!(1611);
+special_sqrt_rem!(3223);
+
+#[test]
+fn sqrt_rem_max() {
+    // act
+    let (the_root, the_remainder) = crate::const_sqrt_rem(&u206265::MAX);
+
+    // assert
+    assert!(is_sqrt_rem(&u206265::MAX, &the_root, &the_remainder));
+}
+
+#[test]
+fn sqrt_rem_small() {
+    // act, assert
+    assert_eq!(
+        crate::const_sqrt_rem(&u206265::ZERO),
+        (u206265::ZERO, u206265::ZERO)
+    );
+    assert_eq!(
+        crate::const_sqrt_rem(&u206265::ONE),
+        (u206265::ONE, u206265::ZERO)
+    );
+    assert_eq!(
+        crate::const_sqrt_rem(&u206265::from(8u8)),
+        (u206265::from(2u8), u206265::from(4u8))
+    );
+}
@@ -24,3 +24,13 @@ quickcheck! {
         }
     }
 }
+
+#[test]
+fn sub_wraps_around() {
+    // act
+    let (the_sub, the_ov) = crate::const_sub(&u206265::ZERO, &u206265::ONE);
+
+    // assert
+    assert!(the_ov, "Subtracting from zero should underflow");
+    assert_eq!(the_sub, u206265::MAX);
+}